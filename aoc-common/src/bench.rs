@@ -0,0 +1,47 @@
+//! Criterion benchmarks of a day's parse step and both parts, against its real input.
+//!
+//! Each day's `benches/dayNN.rs` is a single [`bench_day!`](crate::bench_day) invocation; run
+//! them with `cargo bench -p dayNN`, or `cargo bench` for the whole workspace.
+
+pub use criterion;
+
+/// Defines a `main` that benchmarks `parse`, `part_1` and `part_2` of the day library `$krate`.
+///
+/// The input comes from `inputs/dayNN.txt` or the embedded `INPUT`, as with `aoc run all`; days
+/// without an embedded input say so. Slow days can lower the number of samples taken
+/// (criterion's minimum is 10):
+///
+/// ```ignore
+/// aoc_common::bench_day!(16, day16, sample_size = 10);
+/// aoc_common::bench_day!(19, day19, no embedded input);
+/// ```
+#[macro_export]
+macro_rules! bench_day {
+    ($number:literal, $krate:ident) => {
+        $crate::bench_day!(@bench $number, $krate, Some($krate::INPUT), 100);
+    };
+    ($number:literal, $krate:ident, sample_size = $samples:literal) => {
+        $crate::bench_day!(@bench $number, $krate, Some($krate::INPUT), $samples);
+    };
+    ($number:literal, $krate:ident, no embedded input) => {
+        $crate::bench_day!(@bench $number, $krate, None, 100);
+    };
+    (@bench $number:literal, $krate:ident, $fallback:expr, $samples:literal) => {
+        fn bench(c: &mut $crate::bench::criterion::Criterion) {
+            use std::hint::black_box;
+
+            let text = $crate::input::load_default($number, $fallback).expect("loading input");
+            let input = $krate::parse(&text).expect("parsing input");
+
+            let mut group = c.benchmark_group(stringify!($krate));
+            group.sample_size($samples);
+            group.bench_function("parse", |b| b.iter(|| $krate::parse(black_box(&text))));
+            group.bench_function("part 1", |b| b.iter(|| $krate::part_1(black_box(&input))));
+            group.bench_function("part 2", |b| b.iter(|| $krate::part_2(black_box(&input))));
+            group.finish();
+        }
+
+        $crate::bench::criterion::criterion_group!(benches, bench);
+        $crate::bench::criterion::criterion_main!(benches);
+    };
+}
@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+use thiserror::Error;
+
+use crate::point::Point2;
+
+/// A row that isn't as wide as the ones before it. Lines count from 1.
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+#[error("line {line}: row has {width} cells, expected {expected}")]
+pub struct RaggedRow {
+    pub line: usize,
+    pub width: usize,
+    pub expected: usize,
+}
+
+/// A dense, rectangular grid stored row by row.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, RaggedRow> {
+        let width = rows.first().map_or(0, |r| r.len());
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, r)| r.len() != width) {
+            return Err(RaggedRow {
+                line: y + 1,
+                width: row.len(),
+                expected: width,
+            });
+        }
+
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one row per line, converting each character, and where it was found, with `f`.
+    pub fn parse<E, F>(s: &str, mut f: F) -> Result<Grid<T>, E>
+    where
+        E: From<RaggedRow>,
+        F: FnMut(Point2, char) -> Result<T, E>,
+    {
+        let rows = s
+            .lines()
+            .enumerate()
+            .map(|(y, l)| {
+                l.chars()
+                    .enumerate()
+                    .map(|(x, ch)| f(Point2::new(x as i64, y as i64), ch))
+                    .collect()
+            })
+            .collect::<Result<_, E>>()?;
+        Ok(Grid::from_rows(rows)?)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    pub fn get(&self, p: Point2) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point2) -> Option<&mut T> {
+        self.offset(p).map(move |i| &mut self.cells[i])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Appends a row to the bottom (highest `y`) of the grid.
+    pub fn push_row(&mut self, row: Vec<T>) -> Result<(), RaggedRow> {
+        if self.height > 0 && row.len() != self.width {
+            return Err(RaggedRow {
+                line: self.height + 1,
+                width: row.len(),
+                expected: self.width,
+            });
+        }
+        self.width = row.len();
+        self.height += 1;
+        self.cells.extend(row);
+        Ok(())
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let width = self.width as i64;
+        (0..self.height as i64).flat_map(move |y| (0..width).map(move |x| Point2::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The first point, row by row, whose cell matches `pred`.
+    pub fn position<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Option<Point2> {
+        self.iter().find(|(_, v)| pred(v)).map(|(p, _)| p)
+    }
+
+    /// The orthogonal neighbours of `p` that are inside the grid.
+    pub fn neighbors_4(&self, p: Point2) -> impl Iterator<Item = Point2> + '_ {
+        p.neighbors_4().filter(|n| self.contains(*n))
+    }
+
+    /// The orthogonal and diagonal neighbours of `p` that are inside the grid.
+    pub fn neighbors_8(&self, p: Point2) -> impl Iterator<Item = Point2> + '_ {
+        p.neighbors_8().filter(|n| self.contains(*n))
+    }
+
+    /// The points from `p` (exclusive) to the edge of the grid, stepping by `dir`.
+    pub fn ray(&self, p: Point2, dir: Point2) -> impl Iterator<Item = Point2> + '_ {
+        std::iter::successors(Some(p + dir), move |&n| Some(n + dir))
+            .take_while(|n| self.contains(*n))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn offset(&self, p: Point2) -> Option<usize> {
+        if self.contains(p) {
+            Some(p.y as usize * self.width + p.x as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point2) -> &Self::Output {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", p, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, p: Point2) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", p, width, height))
+    }
+}
+
+/// A grid that only stores the cells that have been set, for unbounded or mostly empty spaces.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    pub fn get(&self, p: Point2) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn insert(&mut self, p: Point2, value: T) -> Option<T> {
+        self.cells.insert(p, value)
+    }
+
+    pub fn remove(&mut self, p: Point2) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.cells.iter().map(|(p, v)| (*p, v))
+    }
+
+    /// The smallest and largest corners of a box containing every set cell.
+    pub fn bounds(&self) -> Option<(Point2, Point2)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(lo, hi), p| {
+            (
+                Point2::new(lo.x.min(p.x), lo.y.min(p.y)),
+                Point2::new(hi.x.max(p.x), hi.y.max(p.y)),
+            )
+        }))
+    }
+}
+
+impl<T> FromIterator<(Point2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(Point2, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point2, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(s: &str) -> Result<Grid<u32>, RaggedRow> {
+        Grid::parse(s, |_, ch| Ok(ch.to_digit(10).unwrap()))
+    }
+
+    #[test]
+    fn parse_is_row_major() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point2::new(2, 0)], 3);
+        assert_eq!(grid[Point2::new(0, 1)], 4);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.get(Point2::new(3, 0)), None);
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        assert_eq!(
+            digits("123\n45\n678"),
+            Err(RaggedRow {
+                line: 2,
+                width: 2,
+                expected: 3
+            })
+        );
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbors_4(Point2::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbors_4(Point2::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors_8(Point2::new(0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn ray_runs_to_the_edge() {
+        let grid = Grid::new(4, 1, 0);
+        let ray: Vec<_> = grid.ray(Point2::new(1, 0), Point2::new(1, 0)).collect();
+        assert_eq!(ray, vec![Point2::new(2, 0), Point2::new(3, 0)]);
+    }
+
+    #[test]
+    fn sparse_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Point2::new(3, -1), 'a');
+        grid.insert(Point2::new(-2, 4), 'b');
+        assert_eq!(
+            grid.bounds(),
+            Some((Point2::new(-2, -1), Point2::new(3, 4)))
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Cursor, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Error, Result};
+
+/// Loads the puzzle input for `day`.
+///
+/// Sources are tried in order:
+/// 1. the path given as the first command line argument (`-` reads stdin),
+/// 2. `inputs/dayNN.txt`, relative to the current directory or the workspace root,
+/// 3. stdin, if something was piped to it,
+/// 4. the embedded `fallback`, if there is one.
+pub fn load(day: u32, fallback: Option<&str>) -> Result<String> {
+    load_from(day, std::env::args().nth(1).as_deref(), fallback)
+}
+
+/// Like [`load`], but with the path argument supplied by the caller.
+pub fn load_from(day: u32, path: Option<&str>, fallback: Option<&str>) -> Result<String> {
+    let raw = match path {
+        Some("-") => read_stdin()?,
+        Some(path) => read_file(Path::new(path))?,
+        None => match default_path(day) {
+            Some(path) => read_file(&path)?,
+            None => {
+                let piped = if io::stdin().is_terminal() {
+                    String::new()
+                } else {
+                    read_stdin()?
+                };
+
+                if !piped.is_empty() {
+                    piped
+                } else {
+                    fallback_or_err(day, fallback)?
+                }
+            }
+        },
+    };
+
+    Ok(trim_trailing_newlines(raw))
+}
+
+/// Like [`load`], but opens the input to be read a line at a time rather than reading it all
+/// into memory.
+pub fn open(day: u32, fallback: Option<&'static str>) -> Result<Box<dyn BufRead>> {
+    open_from(day, std::env::args().nth(1).as_deref(), fallback)
+}
+
+/// Like [`open`], but with the path argument supplied by the caller.
+///
+/// Unlike [`load_from`], trailing newlines are left in place.
+pub fn open_from(
+    day: u32,
+    path: Option<&str>,
+    fallback: Option<&'static str>,
+) -> Result<Box<dyn BufRead>> {
+    match path {
+        Some("-") => Ok(Box::new(io::stdin().lock())),
+        Some(path) => open_file(Path::new(path)),
+        None => match default_path(day) {
+            Some(path) => open_file(&path),
+            None => {
+                if !io::stdin().is_terminal() {
+                    let mut stdin = io::stdin().lock();
+                    if !stdin.fill_buf().context("Reading stdin")?.is_empty() {
+                        return Ok(Box::new(stdin));
+                    }
+                }
+                let fallback = fallback.ok_or_else(|| no_input(day))?;
+                Ok(Box::new(Cursor::new(fallback.as_bytes())))
+            }
+        },
+    }
+}
+
+/// Loads `inputs/dayNN.txt`, or the embedded `fallback` if there's no such file.
+///
+/// Unlike [`load`], this never looks at the command line or stdin, so it's suitable for running
+/// many days in one process.
+pub fn load_default(day: u32, fallback: Option<&str>) -> Result<String> {
+    let raw = match default_path(day) {
+        Some(path) => read_file(&path)?,
+        None => fallback_or_err(day, fallback)?,
+    };
+
+    Ok(trim_trailing_newlines(raw))
+}
+
+/// The conventional location of a day's input, relative to the workspace root.
+pub fn file_name(day: u32) -> PathBuf {
+    PathBuf::from(format!("inputs/day{:02}.txt", day))
+}
+
+fn default_path(day: u32) -> Option<PathBuf> {
+    let relative = file_name(day);
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(&relative);
+    [relative, workspace].into_iter().find(|p| p.is_file())
+}
+
+fn fallback_or_err(day: u32, fallback: Option<&str>) -> Result<String> {
+    fallback.map(str::to_string).ok_or_else(|| no_input(day))
+}
+
+fn no_input(day: u32) -> Error {
+    Error::msg(format!(
+        "No input for day {}: pass a path, pipe it to stdin, or create {}",
+        day,
+        file_name(day).display()
+    ))
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Reading {}", path.display()))
+}
+
+fn open_file(path: &Path) -> Result<Box<dyn BufRead>> {
+    let file = File::open(path).with_context(|| format!("Reading {}", path.display()))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+fn read_stdin() -> Result<String> {
+    let mut buf = String::new();
+    io::stdin()
+        .read_to_string(&mut buf)
+        .context("Reading stdin")?;
+    Ok(buf)
+}
+
+// Input files end with a newline, which the embedded constants never had.
+fn trim_trailing_newlines(mut s: String) -> String {
+    let len = s.trim_end_matches(['\n', '\r']).len();
+    s.truncate(len);
+    s
+}
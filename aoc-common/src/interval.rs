@@ -0,0 +1,308 @@
+use std::fmt::{Display, Formatter};
+
+/// The integers from `start` to `end`, inclusive. It's never empty: `start <= end`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    /// Panics if `start > end`; see [`Interval::try_new`] for intervals that may be empty.
+    pub fn new(start: i64, end: i64) -> Interval {
+        Interval::try_new(start, end)
+            .unwrap_or_else(|| panic!("{}-{} is an empty interval", start, end))
+    }
+
+    /// The interval from `start` to `end`, or `None` if that's empty.
+    pub fn try_new(start: i64, end: i64) -> Option<Interval> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    /// How many integers it holds, saturating at `u64::MAX`.
+    pub fn size(&self) -> u64 {
+        self.start.abs_diff(self.end).saturating_add(1)
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    /// Whether every integer of `other` is in this one.
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether the two share any integer.
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Whether the two don't overlap, but one starts right after the other ends.
+    pub fn is_adjacent(&self, other: &Interval) -> bool {
+        self.end.checked_add(1) == Some(other.start) || other.end.checked_add(1) == Some(self.start)
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::try_new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The one interval covering both, if they overlap or are adjacent.
+    pub fn merge(&self, other: &Interval) -> Option<Interval> {
+        (self.overlaps(other) || self.is_adjacent(other)).then(|| Interval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    pub fn union(&self, other: &Interval) -> IntervalSet {
+        IntervalSet::from_iter([*self, *other])
+    }
+
+    /// The integers of this interval that aren't in `other`.
+    pub fn difference(&self, other: &Interval) -> IntervalSet {
+        if !self.overlaps(other) {
+            return IntervalSet::from(*self);
+        }
+        let before = Interval::try_new(self.start, other.start.saturating_sub(1))
+            .filter(|_| other.start > self.start);
+        let after = Interval::try_new(other.end.saturating_add(1), self.end)
+            .filter(|_| other.end < self.end);
+        before.into_iter().chain(after).collect()
+    }
+}
+
+impl Display for Interval {
+    /// Like `2-4`, as the puzzles write them.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// A set of integers, kept as the fewest intervals that cover it: sorted, and neither
+/// overlapping nor adjacent.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    /// The set's intervals, in order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers it holds, saturating at `u64::MAX`.
+    pub fn size(&self) -> u64 {
+        self.intervals
+            .iter()
+            .fold(0u64, |n, iv| n.saturating_add(iv.size()))
+    }
+
+    /// The smallest interval covering the whole set, if it isn't empty.
+    pub fn hull(&self) -> Option<Interval> {
+        Some(Interval {
+            start: self.intervals.first()?.start,
+            end: self.intervals.last()?.end,
+        })
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        let i = self.intervals.partition_point(|iv| iv.end < x);
+        self.intervals.get(i).is_some_and(|iv| iv.contains(x))
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        // Everything from `lo` up to `hi` merges with the new interval.
+        let lo = self
+            .intervals
+            .partition_point(|iv| iv.end.saturating_add(1) < interval.start);
+        let hi = self
+            .intervals
+            .partition_point(|iv| iv.start <= interval.end.saturating_add(1));
+        let merged = self.intervals[lo..hi].iter().fold(interval, |a, b| {
+            a.merge(b).expect("touching intervals merge")
+        });
+        self.intervals.splice(lo..hi, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+        let mut out = Vec::new();
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            out.extend(x.intersection(y));
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        // Pieces of normalised sets are already sorted and apart.
+        IntervalSet { intervals: out }
+    }
+
+    /// The integers of this set that aren't in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut out = Vec::new();
+        for interval in self.iter() {
+            let mut rest = IntervalSet::from(*interval);
+            for cut in other.overlapping(interval) {
+                rest = rest
+                    .iter()
+                    .flat_map(|piece| piece.difference(cut).intervals)
+                    .collect();
+            }
+            out.extend(rest.intervals);
+        }
+        IntervalSet { intervals: out }
+    }
+
+    /// The set's intervals that overlap `interval`.
+    pub fn overlapping<'a>(&'a self, interval: &'a Interval) -> impl Iterator<Item = &'a Interval> {
+        let lo = self.intervals.partition_point(|iv| iv.end < interval.start);
+        self.intervals[lo..]
+            .iter()
+            .take_while(move |iv| iv.start <= interval.end)
+    }
+
+    /// The integers within `bounds` that aren't in the set.
+    pub fn gaps(&self, bounds: Interval) -> IntervalSet {
+        IntervalSet::from(bounds).difference(self)
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        IntervalSet {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    /// Merges any number of intervals, in any order.
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut sorted: Vec<Interval> = iter.into_iter().collect();
+        sorted.sort_unstable();
+
+        let mut intervals: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut().and_then(|last| last.merge(&interval)) {
+                Some(merged) => *intervals.last_mut().unwrap() = merged,
+                None => intervals.push(interval),
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl<'a> IntoIterator for &'a IntervalSet {
+    type Item = &'a Interval;
+    type IntoIter = std::slice::Iter<'a, Interval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Display for IntervalSet {
+    /// Like `2-4,6-8`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, interval) in self.intervals.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", interval)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iv(start: i64, end: i64) -> Interval {
+        Interval::new(start, end)
+    }
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals.iter().map(|&(s, e)| iv(s, e)).collect()
+    }
+
+    #[test]
+    fn intervals() {
+        assert_eq!(iv(2, 4).size(), 3);
+        assert_eq!(Interval::try_new(5, 4), None);
+        assert!(iv(2, 8).contains_interval(&iv(3, 7)));
+        assert!(!iv(3, 7).contains_interval(&iv(2, 8)));
+        assert!(iv(5, 7).overlaps(&iv(7, 9)));
+        assert!(!iv(2, 3).overlaps(&iv(4, 5)));
+        assert!(iv(2, 3).is_adjacent(&iv(4, 5)));
+        assert!(!iv(2, 3).is_adjacent(&iv(5, 6)));
+        assert_eq!(iv(2, 6).intersection(&iv(4, 8)), Some(iv(4, 6)));
+        assert_eq!(iv(2, 4).intersection(&iv(6, 8)), None);
+        assert_eq!(iv(2, 3).merge(&iv(4, 5)), Some(iv(2, 5)));
+        assert_eq!(iv(2, 3).merge(&iv(5, 6)), None);
+        assert_eq!(iv(2, 3).union(&iv(5, 6)), set(&[(2, 3), (5, 6)]));
+        assert_eq!(iv(i64::MIN, i64::MAX).size(), u64::MAX);
+    }
+
+    #[test]
+    fn differences() {
+        assert_eq!(iv(2, 8).difference(&iv(4, 5)), set(&[(2, 3), (6, 8)]));
+        assert_eq!(iv(2, 8).difference(&iv(0, 5)), set(&[(6, 8)]));
+        assert_eq!(iv(2, 8).difference(&iv(5, 9)), set(&[(2, 4)]));
+        assert_eq!(iv(2, 8).difference(&iv(1, 9)), IntervalSet::new());
+        assert_eq!(iv(2, 8).difference(&iv(10, 12)), set(&[(2, 8)]));
+    }
+
+    #[test]
+    fn merging() {
+        let s = set(&[(6, 8), (2, 4), (5, 5), (12, 14), (13, 20), (30, 30)]);
+        assert_eq!(s.intervals(), &[iv(2, 8), iv(12, 20), iv(30, 30)]);
+        assert_eq!(s.size(), 7 + 9 + 1);
+        assert_eq!(s.hull(), Some(iv(2, 30)));
+        assert!(s.contains(12) && s.contains(30) && !s.contains(9) && !s.contains(31));
+        assert_eq!(s.to_string(), "2-8,12-20,30-30");
+    }
+
+    #[test]
+    fn inserting() {
+        let mut s = set(&[(2, 4), (10, 12), (20, 22)]);
+        s.insert(iv(6, 7));
+        assert_eq!(s, set(&[(2, 4), (6, 7), (10, 12), (20, 22)]));
+        s.insert(iv(5, 11));
+        assert_eq!(s, set(&[(2, 12), (20, 22)]));
+        s.insert(iv(0, 0));
+        s.insert(iv(23, 25));
+        assert_eq!(s, set(&[(0, 0), (2, 12), (20, 25)]));
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 22), (28, 40)]);
+        assert_eq!(a.union(&b), set(&[(0, 40)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 22), (28, 30)]));
+        assert_eq!(a.difference(&b), set(&[(0, 4), (23, 27)]));
+        assert_eq!(b.difference(&a), set(&[(11, 19), (31, 40)]));
+        assert_eq!(a.gaps(iv(-5, 35)), set(&[(-5, -1), (11, 19), (31, 35)]));
+    }
+}
@@ -0,0 +1,24 @@
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod parse;
+pub mod point;
+pub mod search;
+
+pub use grid::{Grid, RaggedRow, SparseGrid};
+pub use interval::{Interval, IntervalSet};
+pub use point::{Point2, Point3};
+
+/// Turns the result of a day's `run` into its exit code, printing the error chain (without a
+/// backtrace) if it failed.
+pub fn report(result: anyhow::Result<()>) -> std::process::ExitCode {
+    match result {
+        Ok(()) => std::process::ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            std::process::ExitCode::FAILURE
+        }
+    }
+}
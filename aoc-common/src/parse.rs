@@ -0,0 +1,94 @@
+use std::str::FromStr;
+
+/// A parse error that knows which line of its input it happened on, counting from 1.
+///
+/// `FromStr` impls report lines relative to the text they were given; this lets the caller
+/// move the error to where that text sits in the whole puzzle input.
+pub trait Located {
+    fn below(self, lines: usize) -> Self;
+}
+
+/// Parses every line of `input` as a `T`, numbering any error by the line it came from.
+pub fn lines<T>(input: &str) -> Result<Vec<T>, T::Err>
+where
+    T: FromStr,
+    T::Err: Located,
+{
+    lines_with(input, str::parse)
+}
+
+/// Parses every line of `input` with `f`, for lines that need more than `FromStr` to read them,
+/// numbering any error by the line it came from.
+pub fn lines_with<T, E, F>(input: &str, mut f: F) -> Result<Vec<T>, E>
+where
+    E: Located,
+    F: FnMut(&str) -> Result<T, E>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| f(l).map_err(|e| e.below(i)))
+        .collect()
+}
+
+/// Where `part` starts within `line`, counting from 1. `part` must be a slice of `line`.
+pub fn column(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    debug_assert!(offset <= line.len(), "{:?} isn't part of {:?}", part, line);
+    offset + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct BadLine(usize);
+
+    impl Located for BadLine {
+        fn below(self, lines: usize) -> Self {
+            BadLine(self.0 + lines)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Even(u32);
+
+    impl FromStr for Even {
+        type Err = BadLine;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.parse() {
+                Ok(n) if n % 2 == 0 => Ok(Even(n)),
+                _ => Err(BadLine(1)),
+            }
+        }
+    }
+
+    #[test]
+    fn lines_numbers_errors_from_1() {
+        assert_eq!(lines::<Even>("2\n4"), Ok(vec![Even(2), Even(4)]));
+        assert_eq!(lines::<Even>("2\n4\n5"), Err(BadLine(3)));
+    }
+
+    #[test]
+    fn lines_with_numbers_errors_from_1() {
+        let below = |limit: u32| {
+            move |l: &str| match l.parse() {
+                Ok(n) if n < limit => Ok(n),
+                _ => Err(BadLine(1)),
+            }
+        };
+        assert_eq!(lines_with("1\n2", below(3)), Ok(vec![1, 2]));
+        assert_eq!(lines_with("1\n2\n3", below(3)), Err(BadLine(3)));
+    }
+
+    #[test]
+    fn column_counts_from_1() {
+        let line = "move 1 from 2";
+        let (_, rest) = line.split_once(' ').unwrap();
+        assert_eq!(column(line, line), 1);
+        assert_eq!(column(line, rest), 6);
+        assert_eq!(column(line, &line[line.len()..]), line.len() + 1);
+    }
+}
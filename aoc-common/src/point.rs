@@ -0,0 +1,150 @@
+use std::ops::{Add, AddAssign, Sub};
+
+/// A point on a 2D grid. `y` grows in whichever direction the puzzle's drawing does.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    /// Offsets to the 4 orthogonal neighbours.
+    pub const ORTHOGONAL: [Point2; 4] = [
+        Point2::new(-1, 0),
+        Point2::new(1, 0),
+        Point2::new(0, -1),
+        Point2::new(0, 1),
+    ];
+
+    /// Offsets to the 8 neighbours, orthogonal and diagonal.
+    pub const ADJACENT: [Point2; 8] = [
+        Point2::new(-1, -1),
+        Point2::new(0, -1),
+        Point2::new(1, -1),
+        Point2::new(-1, 0),
+        Point2::new(1, 0),
+        Point2::new(-1, 1),
+        Point2::new(0, 1),
+        Point2::new(1, 1),
+    ];
+
+    pub const fn new(x: i64, y: i64) -> Point2 {
+        Point2 { x, y }
+    }
+
+    pub fn neighbors_4(self) -> impl Iterator<Item = Point2> {
+        Point2::ORTHOGONAL.into_iter().map(move |d| self + d)
+    }
+
+    pub fn neighbors_8(self) -> impl Iterator<Item = Point2> {
+        Point2::ADJACENT.into_iter().map(move |d| self + d)
+    }
+
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl Add for Point2 {
+    type Output = Point2;
+
+    fn add(self, rhs: Point2) -> Point2 {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, rhs: Point2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Point2;
+
+    fn sub(self, rhs: Point2) -> Point2 {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point2::new(x, y)
+    }
+}
+
+/// A point in 3D space.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    /// Offsets to the 6 face-sharing neighbours.
+    pub const FACES: [Point3; 6] = [
+        Point3::new(-1, 0, 0),
+        Point3::new(1, 0, 0),
+        Point3::new(0, -1, 0),
+        Point3::new(0, 1, 0),
+        Point3::new(0, 0, -1),
+        Point3::new(0, 0, 1),
+    ];
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn neighbors_6(self) -> impl Iterator<Item = Point3> {
+        Point3::FACES.into_iter().map(move |d| self + d)
+    }
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn componentwise_min(self, other: Point3) -> Point3 {
+        Point3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    pub fn componentwise_max(self, other: Point3) -> Point3 {
+        Point3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, rhs: Point3) -> Point3 {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, rhs: Point3) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, rhs: Point3) -> Point3 {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl From<[i64; 3]> for Point3 {
+    fn from([x, y, z]: [i64; 3]) -> Self {
+        Point3::new(x, y, z)
+    }
+}
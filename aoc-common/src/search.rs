@@ -0,0 +1,263 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// What a search found: the cost of every node it reached, how it reached them, and the first
+/// goal it hit, if any.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    costs: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Search<N, C> {
+    fn new() -> Search<N, C> {
+        Search {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|g| self.cost(g))
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// The cheapest known cost to every node reached.
+    pub fn costs(&self) -> &HashMap<N, C> {
+        &self.costs
+    }
+
+    /// The path from whichever source was closest to `node`, both ends included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.goal.as_ref().and_then(|g| self.path_to(g))
+    }
+}
+
+/// Breadth-first search, where every step costs 1.
+///
+/// Stops at the first node for which `is_goal` holds; pass `|_| false` to visit everything
+/// reachable from `starts`.
+pub fn bfs<N, I, FN, FG>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: FN,
+    mut is_goal: FG,
+) -> Search<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> I,
+    FG: FnMut(&N) -> bool,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        let next_cost = search.costs[&node] + 1;
+        for next in neighbors(&node) {
+            if !search.costs.contains_key(&next) {
+                search.costs.insert(next.clone(), next_cost);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm, for neighbours with a non-negative cost to step to.
+///
+/// Stops at the first node for which `is_goal` holds, like [`bfs`].
+pub fn dijkstra<N, C, I, FN, FG>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: FN,
+    is_goal: FG,
+) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+    FN: FnMut(&N) -> I,
+    FG: FnMut(&N) -> bool,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost to a goal, and should be
+/// consistent, or the costs found may not be the cheapest.
+pub fn astar<N, C, I, FN, FH, FG>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+    FN: FnMut(&N) -> I,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let mut search = Search::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), C::default());
+            heap.push(Frontier {
+                priority: heuristic(&start),
+                cost: C::default(),
+                node: start,
+            });
+        }
+    }
+
+    while let Some(Frontier { cost, node, .. }) = heap.pop() {
+        // A cheaper way here was found after this entry was queued.
+        if cost > search.costs[&node] {
+            continue;
+        }
+
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            if search.costs.get(&next).is_none_or(|&c| next_cost < c) {
+                search.costs.insert(next.clone(), next_cost);
+                search.parents.insert(next.clone(), node.clone());
+                heap.push(Frontier {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    search
+}
+
+/// A queued node, ordered so that `BinaryHeap` pops the lowest priority first.
+struct Frontier<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Frontier<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Frontier<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Frontier<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Frontier<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A line of nodes 0..10, where stepping right costs 1 and a shortcut from 0 to 5 costs 3.
+    fn line(n: &u32) -> Vec<(u32, u32)> {
+        let mut next = Vec::new();
+        if *n < 9 {
+            next.push((n + 1, 1));
+        }
+        if *n == 0 {
+            next.push((5, 3));
+        }
+        next
+    }
+
+    #[test]
+    fn bfs_finds_fewest_steps_and_path() {
+        let search = bfs([0], |n| line(n).into_iter().map(|(n, _)| n), |n| *n == 7);
+        assert_eq!(search.goal(), Some(&7));
+        assert_eq!(search.goal_cost(), Some(3));
+        assert_eq!(search.path(), Some(vec![0, 5, 6, 7]));
+    }
+
+    #[test]
+    fn bfs_from_several_sources() {
+        let search = bfs([0, 8], |n| line(n).into_iter().map(|(n, _)| n), |n| *n == 9);
+        assert_eq!(search.goal_cost(), Some(1));
+        assert_eq!(search.path(), Some(vec![8, 9]));
+    }
+
+    #[test]
+    fn bfs_without_a_goal_visits_everything() {
+        let search = bfs([3], |n| line(n).into_iter().map(|(n, _)| n), |_| false);
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.costs().len(), 7);
+        assert_eq!(search.cost(&9), Some(6));
+        assert_eq!(search.path_to(&0), None);
+    }
+
+    #[test]
+    fn dijkstra_weighs_steps() {
+        let search = dijkstra([0], line, |n| *n == 7);
+        assert_eq!(search.goal_cost(), Some(5));
+        assert_eq!(search.path(), Some(vec![0, 5, 6, 7]));
+
+        let search = dijkstra([0], line, |n| *n == 4);
+        assert_eq!(search.goal_cost(), Some(4));
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let search = astar([0], line, |n| 9u32.saturating_sub(*n) / 2, |n| *n == 9);
+        assert_eq!(search.goal_cost(), Some(7));
+        assert_eq!(search.path(), Some(vec![0, 5, 6, 7, 8, 9]));
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Context, Error, Result};
+use serde::Deserialize;
+
+use crate::registry::Part;
+
+/// The answers we know about for each day and part: the one that was accepted, if any, and every
+/// one that was rejected.
+///
+/// It lives in `answers.toml` at the workspace root, with a table per part:
+///
+/// ```toml
+/// [day16.part1]
+/// answer = "1880"
+/// wrong = ["1447"]
+/// ```
+#[derive(Debug, Default)]
+pub struct Ledger {
+    entries: HashMap<(u32, Part), Entry>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Entry {
+    answer: Option<String>,
+    #[serde(default)]
+    wrong: Vec<String>,
+}
+
+/// How an answer compares with the ledger.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Check<'a> {
+    /// The ledger has no accepted answer for this part, and doesn't know this one to be wrong.
+    Unchecked,
+    Correct,
+    /// It differs from the accepted answer.
+    Incorrect {
+        expected: &'a str,
+    },
+    /// It was submitted before and rejected.
+    KnownWrong,
+}
+
+impl Check<'_> {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Check::Unchecked | Check::Correct)
+    }
+
+    /// The name used for this check in JSON and TSV output.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Check::Unchecked => "unchecked",
+            Check::Correct => "correct",
+            Check::Incorrect { .. } => "incorrect",
+            Check::KnownWrong => "known_wrong",
+        }
+    }
+}
+
+impl Ledger {
+    /// Reads `answers.toml` from the current directory or the workspace root. Having no ledger
+    /// at all is fine, and checks nothing.
+    pub fn load() -> Result<Ledger> {
+        let relative = PathBuf::from("answers.toml");
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(&relative);
+
+        match [relative, workspace].into_iter().find(|p| p.is_file()) {
+            Some(path) => {
+                let text = fs::read_to_string(&path)
+                    .with_context(|| format!("Reading {}", path.display()))?;
+                text.parse()
+                    .with_context(|| format!("Reading {}", path.display()))
+            }
+            None => Ok(Ledger::default()),
+        }
+    }
+
+    pub fn check(&self, day: u32, part: Part, value: &str) -> Check<'_> {
+        let entry = match self.entries.get(&(day, part)) {
+            Some(entry) => entry,
+            None => return Check::Unchecked,
+        };
+
+        if entry.wrong.iter().any(|w| w == value) {
+            return Check::KnownWrong;
+        }
+        match &entry.answer {
+            Some(answer) if answer == value => Check::Correct,
+            Some(answer) => Check::Incorrect { expected: answer },
+            None => Check::Unchecked,
+        }
+    }
+}
+
+impl FromStr for Ledger {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let days: BTreeMap<String, BTreeMap<String, Entry>> = toml::from_str(s)?;
+
+        let mut entries = HashMap::new();
+        for (day_key, parts) in days {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|n| n.parse().ok())
+                .ok_or_else(|| {
+                    Error::msg(format!(
+                        "expected a table like [day01], found [{}]",
+                        day_key
+                    ))
+                })?;
+            for (part_key, entry) in parts {
+                let part = part_key
+                    .strip_prefix("part")
+                    .and_then(|n| n.parse().ok())
+                    .ok_or_else(|| {
+                        Error::msg(format!(
+                            "expected a table like [{}.part1], found [{}.{}]",
+                            day_key, day_key, part_key
+                        ))
+                    })?;
+                entries.insert((day, part), entry);
+            }
+        }
+
+        Ok(Ledger { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEDGER: &str = r#"
+[day10.part1]
+answer = "14780"
+wrong = ["13340"]
+
+[day10.part2]
+answer = '''
+#..#
+.##.'''
+
+[day18.part2]
+wrong = ["1319", "2528"]
+"#;
+
+    #[test]
+    fn checks() -> Result<()> {
+        let ledger: Ledger = LEDGER.parse()?;
+        assert_eq!(ledger.check(10, Part::One, "14780"), Check::Correct);
+        assert_eq!(ledger.check(10, Part::One, "13340"), Check::KnownWrong);
+        assert_eq!(
+            ledger.check(10, Part::One, "14781"),
+            Check::Incorrect { expected: "14780" }
+        );
+        assert_eq!(ledger.check(10, Part::Two, "#..#\n.##."), Check::Correct);
+        assert_eq!(ledger.check(18, Part::Two, "2528"), Check::KnownWrong);
+        assert_eq!(ledger.check(18, Part::Two, "2530"), Check::Unchecked);
+        assert_eq!(ledger.check(1, Part::One, "1"), Check::Unchecked);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_tables() {
+        assert!("[ten.part1]\nanswer = \"1\"".parse::<Ledger>().is_err());
+        assert!("[day10.one]\nanswer = \"1\"".parse::<Ledger>().is_err());
+        assert!("[day10.part1]\nanswer = 1".parse::<Ledger>().is_err());
+        assert!("[day10.part1]\nanswr = \"1\"".parse::<Ledger>().is_err());
+    }
+}
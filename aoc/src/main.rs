@@ -0,0 +1,156 @@
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use anyhow::{Error, Result};
+use clap::{Parser, Subcommand};
+
+use crate::ledger::Ledger;
+use crate::output::Format;
+use crate::registry::{Part, DAYS};
+
+mod ledger;
+mod output;
+mod registry;
+mod scaffold;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every day
+    Run {
+        /// Day number, or `all`
+        day: Selection,
+        /// Run only this part (1 or 2)
+        #[arg(long)]
+        part: Option<Part>,
+        /// Read the input from this path (`-` for stdin) instead of the default
+        #[arg(long)]
+        input: Option<String>,
+        /// Report how long parsing and each part took
+        #[arg(long)]
+        time: bool,
+        /// How to print the results
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Create `dayNN` from the template and register it with the runner
+    New {
+        /// Day number
+        day: u32,
+    },
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Selection {
+    All,
+    Day(u32),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            Ok(Selection::All)
+        } else {
+            s.parse()
+                .map(Selection::Day)
+                .map_err(|_| format!("expected a day number or `all`, got {:?}", s))
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            time,
+            format,
+        } => {
+            let parts = part.map_or(Part::BOTH.to_vec(), |p| vec![p]);
+            match day {
+                Selection::Day(n) => run_day(n, &parts, input.as_deref(), time, format),
+                Selection::All if input.is_some() => {
+                    Err(Error::msg("--input can't be used with `all`"))
+                }
+                Selection::All => run_all(&parts, time, format),
+            }
+        }
+        Command::New { day } => new_day(day),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(number: u32) -> Result<bool> {
+    let scaffold = scaffold::new_day(number)?;
+    for path in &scaffold.created {
+        println!("Created {}", path.display());
+    }
+    for path in &scaffold.updated {
+        println!("Updated {}", path.display());
+    }
+    Ok(true)
+}
+
+/// Runs a single day, checking its answers against the ledger unless it was given some other
+/// input. Returns whether every part succeeded and passed.
+fn run_day(
+    number: u32,
+    parts: &[Part],
+    input: Option<&str>,
+    time: bool,
+    format: Format,
+) -> Result<bool> {
+    let day = registry::find(number).ok_or_else(|| Error::msg(format!("No day {}", number)))?;
+    let text = aoc_common::input::load_from(day.number, input, day.input)?;
+    let ledger = match input {
+        Some(_) => Ledger::default(),
+        None => Ledger::load()?,
+    };
+    let solution = (day.solve)(&text, parts)?;
+
+    let rows = [(day, Ok(solution))];
+    match (format, &rows) {
+        (Format::Text, [(day, Ok(solution))]) => output::print_day(day, solution, &ledger, time),
+        _ => output::print_records(&rows, &ledger, format)?,
+    }
+
+    Ok(output::all_ok(&rows, &ledger))
+}
+
+/// Runs every registered day and prints all their answers, checked against the ledger.
+fn run_all(parts: &[Part], time: bool, format: Format) -> Result<bool> {
+    let ledger = Ledger::load()?;
+
+    let mut rows = Vec::new();
+    for day in DAYS {
+        let solution = aoc_common::input::load_default(day.number, day.input)
+            .and_then(|text| (day.solve)(&text, parts));
+        rows.push((day, solution));
+    }
+
+    match format {
+        Format::Text => output::print_table(&rows, &ledger, time),
+        _ => output::print_records(&rows, &ledger, format)?,
+    }
+
+    Ok(output::all_ok(&rows, &ledger))
+}
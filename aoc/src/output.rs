@@ -0,0 +1,273 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::ledger::{Check, Ledger};
+use crate::registry::{Day, Solution};
+
+/// How the runner prints its results.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Answers for people to read
+    Text,
+    /// A JSON array with a record per day and part
+    Json,
+    /// Tab-separated records with a header row
+    Tsv,
+}
+
+/// A day's solution, or why it couldn't be solved at all.
+pub type Row<'a> = (&'a Day, Result<Solution>);
+
+/// Whether every day was solved, with no part failing or disagreeing with the ledger.
+pub fn all_ok(rows: &[Row], ledger: &Ledger) -> bool {
+    rows.iter().all(|(day, solution)| match solution {
+        Ok(solution) => solution.answers.iter().all(|a| match &a.value {
+            Ok(value) => ledger.check(day.number, a.part, value).is_ok(),
+            Err(_) => false,
+        }),
+        Err(_) => false,
+    })
+}
+
+/// Prints a record per day and part in one of the machine-readable formats.
+pub fn print_records(rows: &[Row], ledger: &Ledger, format: Format) -> Result<()> {
+    match format {
+        Format::Text => unreachable!("text output isn't made of records"),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&records(rows, ledger))?),
+        Format::Tsv => print_tsv(rows, ledger),
+    }
+    Ok(())
+}
+
+/// What to say about a check in text output, if anything.
+fn remark(check: Check) -> Option<String> {
+    match check {
+        Check::Unchecked | Check::Correct => None,
+        Check::Incorrect { expected } if expected.contains('\n') => {
+            Some("incorrect, expected:\n".to_string() + expected)
+        }
+        Check::Incorrect { expected } => Some(format!("incorrect, expected {}", expected)),
+        Check::KnownWrong => Some("already known to be wrong".to_string()),
+    }
+}
+
+/// Prints one day's answers, flagging any the ledger disagrees with.
+pub fn print_day(day: &Day, solution: &Solution, ledger: &Ledger, time: bool) {
+    if time {
+        println!("Parse: {:.2?}", solution.parse);
+    }
+
+    for answer in &solution.answers {
+        let elapsed = if time {
+            format!(" ({:.2?})", answer.elapsed)
+        } else {
+            String::new()
+        };
+        match &answer.value {
+            Ok(value) => {
+                if value.contains('\n') {
+                    println!("Part {}{}:\n{}", answer.part, elapsed, value)
+                } else {
+                    println!("Part {}: {}{}", answer.part, value, elapsed)
+                }
+                if let Some(remark) = remark(ledger.check(day.number, answer.part, value)) {
+                    println!("  ^ {}", remark);
+                }
+            }
+            Err(e) => println!("Part {}: failed: {:#}{}", answer.part, e, elapsed),
+        }
+    }
+
+    if time {
+        println!("Total: {:.2?}", total(solution));
+    }
+}
+
+fn total(solution: &Solution) -> Duration {
+    solution.parse + solution.answers.iter().map(|a| a.elapsed).sum::<Duration>()
+}
+
+/// Prints a table of every day's answers, flagging any the ledger disagrees with.
+pub fn print_table(rows: &[Row], ledger: &Ledger, time: bool) {
+    let mut cells: Vec<[String; 5]> = Vec::new();
+    for (day, solution) in rows {
+        match solution {
+            Ok(solution) => {
+                if time {
+                    cells.push([
+                        day.number.to_string(),
+                        "parse".to_string(),
+                        String::new(),
+                        format!("{:.2?}", solution.parse),
+                        String::new(),
+                    ]);
+                }
+                for answer in &solution.answers {
+                    let (value, check) = match &answer.value {
+                        Ok(value) => {
+                            let check = ledger.check(day.number, answer.part, value);
+                            (value.clone(), remark(check).unwrap_or_default())
+                        }
+                        Err(e) => (format!("failed: {:#}", e), String::new()),
+                    };
+                    let mut lines = value.lines();
+                    let mut checks = check.lines();
+                    cells.push([
+                        day.number.to_string(),
+                        answer.part.to_string(),
+                        lines.next().unwrap_or_default().to_string(),
+                        format!("{:.2?}", answer.elapsed),
+                        checks.next().unwrap_or_default().to_string(),
+                    ]);
+                    loop {
+                        let (line, check) = (lines.next(), checks.next());
+                        if line.is_none() && check.is_none() {
+                            break;
+                        }
+                        cells.push([
+                            String::new(),
+                            String::new(),
+                            line.unwrap_or_default().to_string(),
+                            String::new(),
+                            check.unwrap_or_default().to_string(),
+                        ]);
+                    }
+                }
+            }
+            Err(e) => cells.push([
+                day.number.to_string(),
+                String::new(),
+                format!("failed: {:#}", e),
+                String::new(),
+                String::new(),
+            ]),
+        }
+    }
+
+    let width = cells
+        .iter()
+        .map(|c| c[2].len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    if !time {
+        println!("Day  Part  {:<width$}  Check", "Answer");
+        for [day, part, answer, _, check] in cells {
+            let line = format!("{:>3}  {:>4}  {:<width$}  {}", day, part, answer, check);
+            println!("{}", line.trim_end());
+        }
+        return;
+    }
+
+    println!("Day   Part  {:<width$}  {:>10}  Check", "Answer", "Time");
+    for [day, part, answer, time, check] in cells {
+        let line = format!(
+            "{:>3}  {:>5}  {:<width$}  {:>10}  {}",
+            day, part, answer, time, check
+        );
+        println!("{}", line.trim_end());
+    }
+
+    let total: Duration = rows
+        .iter()
+        .filter_map(|(_, solution)| solution.as_ref().ok())
+        .map(total)
+        .sum();
+    println!(
+        "{:>3}  {:>5}  {:<width$}  {:>10}",
+        "",
+        "",
+        "Total",
+        format!("{:.2?}", total)
+    );
+}
+
+/// One part's result, as written by the JSON and TSV formats. A day that couldn't be parsed gets
+/// a single record with no part.
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    day: u32,
+    part: Option<u32>,
+    answer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    duration_ns: Option<u64>,
+    /// `failed` if there's no answer, or else how it compares with the ledger.
+    check: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<&'a str>,
+}
+
+fn records<'a>(rows: &'a [Row], ledger: &'a Ledger) -> Vec<Record<'a>> {
+    let mut records = Vec::new();
+    for (day, solution) in rows {
+        let solution = match solution {
+            Ok(solution) => solution,
+            Err(e) => {
+                records.push(Record {
+                    day: day.number,
+                    part: None,
+                    answer: None,
+                    error: Some(format!("{:#}", e)),
+                    duration_ns: None,
+                    check: "failed",
+                    expected: None,
+                });
+                continue;
+            }
+        };
+
+        for answer in &solution.answers {
+            let mut record = Record {
+                day: day.number,
+                part: Some(answer.part.number()),
+                answer: None,
+                error: None,
+                duration_ns: Some(answer.elapsed.as_nanos() as u64),
+                check: "failed",
+                expected: None,
+            };
+            match &answer.value {
+                Ok(value) => {
+                    let check = ledger.check(day.number, answer.part, value);
+                    record.answer = Some(value);
+                    record.check = check.name();
+                    if let Check::Incorrect { expected } = check {
+                        record.expected = Some(expected);
+                    }
+                }
+                Err(e) => record.error = Some(format!("{:#}", e)),
+            }
+            records.push(record);
+        }
+    }
+    records
+}
+
+fn print_tsv(rows: &[Row], ledger: &Ledger) {
+    // Multi-line answers, like day 10's picture, are escaped to keep to one line per record.
+    let escape = |s: &str| {
+        s.replace('\\', "\\\\")
+            .replace('\n', "\\n")
+            .replace('\t', "\\t")
+    };
+    let field = |f: Option<String>| f.unwrap_or_default();
+
+    println!("day\tpart\tanswer\terror\tduration_ns\tcheck\texpected");
+    for r in records(rows, ledger) {
+        println!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            r.day,
+            field(r.part.map(|p| p.to_string())),
+            field(r.answer.map(escape)),
+            field(r.error.as_deref().map(escape)),
+            field(r.duration_ns.map(|d| d.to_string())),
+            r.check,
+            field(r.expected.map(escape)),
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("no part {:?}, expected 1 or 2", s)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub struct Answer {
+    pub part: Part,
+    pub value: Result<String>,
+    pub elapsed: Duration,
+}
+
+/// What a day produced from one input: how long parsing took, then an answer for each part.
+pub struct Solution {
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// A day's solver, with its parse step and both parts erased to work on strings.
+pub struct Day {
+    pub number: u32,
+    pub input: Option<&'static str>,
+    pub solve: fn(&str, &[Part]) -> Result<Solution>,
+}
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+/// Parses `text` once and runs each of `parts` against it, timing the parse and each part
+/// separately.
+///
+/// A failed parse fails the whole day; a failed part is reported in its [`Answer`].
+fn solve<I, E, A, B, P, F1, F2>(
+    text: &str,
+    parts: &[Part],
+    parse: P,
+    part_1: F1,
+    part_2: F2,
+) -> Result<Solution>
+where
+    E: Into<anyhow::Error>,
+    A: Display,
+    B: Display,
+    P: Fn(&str) -> Result<I, E>,
+    F1: Fn(&I) -> Result<A>,
+    F2: Fn(&I) -> Result<B>,
+{
+    let start = Instant::now();
+    let input = parse(text).map_err(Into::into)?;
+    let parse = start.elapsed();
+
+    let mut answers = Vec::new();
+    for &part in parts {
+        let start = Instant::now();
+        let value = match part {
+            Part::One => part_1(&input).map(|a| a.to_string()),
+            Part::Two => part_2(&input).map(|a| a.to_string()),
+        };
+        answers.push(Answer {
+            part,
+            value,
+            elapsed: start.elapsed(),
+        });
+    }
+
+    Ok(Solution { parse, answers })
+}
+
+/// Registers each day library. Days that don't embed their input as `INPUT` are marked
+/// `(no embedded input)`, and must be given one at runtime.
+macro_rules! days {
+    (@input $krate:ident) => {
+        Some($krate::INPUT)
+    };
+    (@input $krate:ident (no embedded input)) => {
+        None
+    };
+    ($($number:literal => $krate:ident $(($($marker:tt)*))?),* $(,)?) => {
+        pub const DAYS: &[Day] = &[
+            $(Day {
+                number: $number,
+                input: days!(@input $krate $(($($marker)*))?),
+                solve: |text, parts| {
+                    solve(
+                        text,
+                        parts,
+                        $krate::parse,
+                        |input| $krate::part_1(input),
+                        |input| $krate::part_2(input),
+                    )
+                },
+            }),*
+        ];
+    };
+}
+
+days! {
+    1 => day01,
+    2 => day02,
+    3 => day03,
+    4 => day04,
+    5 => day05,
+    6 => day06,
+    7 => day07,
+    8 => day08,
+    9 => day09,
+    10 => day10,
+    11 => day11,
+    12 => day12,
+    13 => day13,
+    14 => day14,
+    15 => day15,
+    16 => day16,
+    17 => day17,
+    18 => day18,
+}
@@ -0,0 +1,210 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Error, Result};
+
+/// What [`new_day`] did to the workspace, with paths relative to its root.
+pub struct Scaffold {
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+}
+
+/// Creates `dayNN` from `template/`, registers it with the runner, and creates an empty
+/// `inputs/dayNN.txt` for it if there isn't one already.
+///
+/// Nothing is written unless the day is new and both registrations can be made.
+pub fn new_day(number: u32) -> Result<Scaffold> {
+    if !(1..=25).contains(&number) {
+        return Err(Error::msg(format!(
+            "There's no day {}, expected 1 to 25",
+            number
+        )));
+    }
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let name = crate_name(number);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(Error::msg(format!("{} already exists", dir.display())));
+    }
+
+    let manifest_path = root.join("aoc").join("Cargo.toml");
+    let registry_path = root.join("aoc").join("src").join("registry.rs");
+    let manifest = register_dependency(&read(&manifest_path)?, number)
+        .with_context(|| format!("Registering {} in {}", name, manifest_path.display()))?;
+    let registry = register_day(&read(&registry_path)?, number)
+        .with_context(|| format!("Registering {} in {}", name, registry_path.display()))?;
+
+    let mut created = Vec::new();
+    copy_template(&root.join("template"), &dir, number, &mut created)?;
+    write(&manifest_path, &manifest)?;
+    write(&registry_path, &registry)?;
+
+    let input = root.join(aoc_common::input::file_name(number));
+    if !input.exists() {
+        if let Some(parent) = input.parent() {
+            fs::create_dir_all(parent).with_context(|| format!("Creating {}", parent.display()))?;
+        }
+        write(&input, "")?;
+        created.push(input);
+    }
+
+    let relative = |path: PathBuf| {
+        path.strip_prefix(&root)
+            .map_or(path.clone(), Path::to_path_buf)
+    };
+    Ok(Scaffold {
+        created: created.into_iter().map(relative).collect(),
+        updated: vec![relative(manifest_path), relative(registry_path)],
+    })
+}
+
+fn crate_name(number: u32) -> String {
+    format!("day{:02}", number)
+}
+
+/// Fills in the template's placeholders: `dayNN` for the crate name, then `NN` for the number.
+fn fill(text: &str, number: u32) -> String {
+    text.replace("dayNN", &crate_name(number))
+        .replace("NN", &number.to_string())
+}
+
+fn copy_template(from: &Path, to: &Path, number: u32, created: &mut Vec<PathBuf>) -> Result<()> {
+    fs::create_dir_all(to).with_context(|| format!("Creating {}", to.display()))?;
+
+    let entries = fs::read_dir(from).with_context(|| format!("Reading {}", from.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        let file_name = path.file_name().unwrap().to_string_lossy();
+        let target = to.join(fill(&file_name, number));
+
+        if path.is_dir() {
+            copy_template(&path, &target, number, created)?;
+        } else {
+            write(&target, &fill(&read(&path)?, number))?;
+            created.push(target);
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds `dayNN = { path = "../dayNN" }` to the runner's dependencies.
+fn register_dependency(manifest: &str, number: u32) -> Result<String> {
+    let name = crate_name(number);
+    insert_entry(
+        manifest,
+        number,
+        &format!("{} = {{ path = \"../{}\" }}", name, name),
+        |line| {
+            let (krate, _) = line.split_once(" = ")?;
+            krate.strip_prefix("day")?.parse().ok()
+        },
+    )
+}
+
+/// Adds `NN => dayNN (no embedded input),` to the `days!` invocation.
+fn register_day(registry: &str, number: u32) -> Result<String> {
+    insert_entry(
+        registry,
+        number,
+        &format!(
+            "    {} => {} (no embedded input),",
+            number,
+            crate_name(number)
+        ),
+        |line| line.trim().split_once(" => ")?.0.parse().ok(),
+    )
+}
+
+/// Inserts `new` among the lines of `text` for which `entry` gives a day number, keeping them in
+/// order.
+fn insert_entry<F>(text: &str, number: u32, new: &str, entry: F) -> Result<String>
+where
+    F: Fn(&str) -> Option<u32>,
+{
+    let mut lines: Vec<&str> = text.lines().collect();
+    let entries: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| entry(l).map(|n| (i, n)))
+        .collect();
+
+    if entries.iter().any(|&(_, n)| n == number) {
+        return Err(Error::msg(format!("Day {} is already registered", number)));
+    }
+    let at = match entries.iter().rev().find(|&&(_, n)| n < number) {
+        Some(&(i, _)) => i + 1,
+        None => match entries.first() {
+            Some(&(i, _)) => i,
+            None => return Err(Error::msg("Found no other days to put it next to")),
+        },
+    };
+
+    lines.insert(at, new);
+    let mut out = lines.join("\n");
+    if text.ends_with('\n') {
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Reading {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).with_context(|| format!("Writing {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[dependencies]
+anyhow = "1.0.44"
+day01 = { path = "../day01" }
+day03 = { path = "../day03" }
+"#;
+
+    const REGISTRY: &str = r#"days! {
+    1 => day01,
+    3 => day03,
+}
+"#;
+
+    #[test]
+    fn fill_placeholders() {
+        assert_eq!(
+            fill("dayNN::parse; load(NN, None)", 7),
+            "day07::parse; load(7, None)"
+        );
+    }
+
+    #[test]
+    fn dependencies_stay_in_order() -> Result<()> {
+        let manifest = register_dependency(MANIFEST, 2)?;
+        assert!(manifest.ends_with(
+            "day01 = { path = \"../day01\" }\nday02 = { path = \"../day02\" }\nday03 = { path = \"../day03\" }\n"
+        ));
+
+        let manifest = register_dependency(MANIFEST, 19)?;
+        assert!(manifest.ends_with("day19 = { path = \"../day19\" }\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn days_stay_in_order() -> Result<()> {
+        assert_eq!(
+            register_day(REGISTRY, 2)?,
+            "days! {\n    1 => day01,\n    2 => day02 (no embedded input),\n    3 => day03,\n}\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn days_are_registered_once() {
+        assert!(register_day(REGISTRY, 3).is_err());
+        assert!(register_dependency(MANIFEST, 1).is_err());
+    }
+}
@@ -0,0 +1,17 @@
+use std::process::ExitCode;
+
+use anyhow::{Error, Result};
+
+fn main() -> ExitCode {
+    aoc_common::report(run())
+}
+
+fn run() -> Result<()> {
+    let text = aoc_common::input::load(1, Some(day01::INPUT))?;
+    let input = day01::parse(&text)?;
+    let report = day01::Report::new(&input).ok_or_else(|| Error::msg("No elves"))?;
+
+    print!("{}", report);
+
+    Ok(())
+}
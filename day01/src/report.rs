@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use crate::{elf_cals, Elf};
+
+/// The percentiles a report lists.
+pub const PERCENTILES: [u32; 6] = [10, 25, 50, 75, 90, 99];
+
+const HISTOGRAM_BINS: u64 = 10;
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Summary statistics of the calories the elves are carrying, for sanity-checking an input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    /// Every elf, most calories first, so that `ranking[0]` holds rank 1. Of elves carrying the
+    /// same, the earlier one ranks higher.
+    pub ranking: Vec<Elf>,
+    pub mean: f64,
+    pub median: f64,
+    /// Each of [`PERCENTILES`], with its nearest-rank value.
+    pub percentiles: Vec<(u32, u64)>,
+    /// How many elves carry each number of items.
+    pub items_per_elf: BTreeMap<usize, usize>,
+    /// Ranges of totals, from `.0` to `.1` inclusive, and how many elves fall in each.
+    pub histogram: Vec<(u64, u64, usize)>,
+}
+
+impl Report {
+    /// Reports on `input`, or `None` if there are no elves in it.
+    pub fn new(input: &[Vec<u64>]) -> Option<Report> {
+        let totals = elf_cals(input);
+        if totals.is_empty() {
+            return None;
+        }
+
+        let mut ranking: Vec<Elf> = totals
+            .iter()
+            .enumerate()
+            .map(|(index, &calories)| Elf { index, calories })
+            .collect();
+        ranking.sort_by_key(|e| std::cmp::Reverse(e.calories));
+
+        let mut sorted = totals.clone();
+        sorted.sort_unstable();
+        let n = sorted.len();
+
+        let mean = sorted.iter().map(|&t| t as f64).sum::<f64>() / n as f64;
+        let median = if n % 2 == 1 {
+            sorted[n / 2] as f64
+        } else {
+            (sorted[n / 2 - 1] as f64 + sorted[n / 2] as f64) / 2.0
+        };
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&p| {
+                let rank = (p as usize * n).div_ceil(100).max(1);
+                (p, sorted[rank - 1])
+            })
+            .collect();
+
+        let mut items_per_elf = BTreeMap::new();
+        for elf in input {
+            *items_per_elf.entry(elf.len()).or_insert(0) += 1;
+        }
+
+        Some(Report {
+            ranking,
+            mean,
+            median,
+            percentiles,
+            items_per_elf,
+            histogram: histogram(&sorted),
+        })
+    }
+
+    pub fn elf_count(&self) -> usize {
+        self.ranking.len()
+    }
+
+    /// The rank of the elf at `index` in the input, counting from 1.
+    pub fn rank_of(&self, index: usize) -> Option<usize> {
+        self.ranking
+            .iter()
+            .position(|e| e.index == index)
+            .map(|r| r + 1)
+    }
+}
+
+/// Splits the range of `sorted` into equal bins and counts the totals in each.
+fn histogram(sorted: &[u64]) -> Vec<(u64, u64, usize)> {
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    let width = ((max - min) / HISTOGRAM_BINS + 1).max(1);
+
+    let mut bins = Vec::new();
+    let mut lo = min;
+    loop {
+        let hi = lo.saturating_add(width - 1).min(max);
+        let count = sorted.iter().filter(|&&t| lo <= t && t <= hi).count();
+        bins.push((lo, hi, count));
+        if hi == max {
+            break;
+        }
+        lo = hi + 1;
+    }
+    bins
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Elves: {}", self.elf_count())?;
+        writeln!(f, "Mean: {:.1}", self.mean)?;
+        writeln!(f, "Median: {:.1}", self.median)?;
+        for (p, value) in &self.percentiles {
+            writeln!(f, "p{}: {}", p, value)?;
+        }
+
+        writeln!(f, "\nItems per elf:")?;
+        for (items, elves) in &self.items_per_elf {
+            writeln!(f, "{:>5}  {}", items, elves)?;
+        }
+
+        writeln!(f, "\nTotals:")?;
+        let most = self.histogram.iter().map(|b| b.2).max().unwrap_or(0);
+        let label_width = self.histogram.last().map_or(0, |b| b.1.to_string().len());
+        for &(lo, hi, count) in &self.histogram {
+            let bar = (count * HISTOGRAM_WIDTH).div_ceil(most.max(1));
+            writeln!(
+                f,
+                "{:>w$}-{:>w$}  {:<bw$}  {}",
+                lo,
+                hi,
+                "#".repeat(bar),
+                count,
+                w = label_width,
+                bw = HISTOGRAM_WIDTH
+            )?;
+        }
+
+        writeln!(f, "\nRank   Elf  Calories")?;
+        for (rank, elf) in self.ranking.iter().enumerate() {
+            writeln!(f, "{:>4}  {:>4}  {:>8}", rank + 1, elf.index, elf.calories)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, TEST};
+    use anyhow::Result;
+
+    #[test]
+    fn sample_report() -> Result<()> {
+        let report = Report::new(&parse(TEST)?).unwrap();
+        assert_eq!(report.elf_count(), 5);
+        assert_eq!(report.mean, 11000.0);
+        assert_eq!(report.median, 10000.0);
+        assert_eq!(
+            report.percentiles,
+            vec![
+                (10, 4000),
+                (25, 6000),
+                (50, 10000),
+                (75, 11000),
+                (90, 24000),
+                (99, 24000)
+            ]
+        );
+        assert_eq!(
+            report.items_per_elf,
+            BTreeMap::from([(1, 2), (2, 1), (3, 2)])
+        );
+        let ranked: Vec<usize> = report.ranking.iter().map(|e| e.index).collect();
+        assert_eq!(ranked, vec![3, 2, 4, 0, 1]);
+        assert_eq!(report.rank_of(0), Some(4));
+        Ok(())
+    }
+
+    #[test]
+    fn histogram_covers_every_total() {
+        let bins = histogram(&[4000, 6000, 10000, 11000, 24000]);
+        assert_eq!(bins.first().map(|b| b.0), Some(4000));
+        assert_eq!(bins.last().map(|b| b.1), Some(24000));
+        assert_eq!(bins.iter().map(|b| b.2).sum::<usize>(), 5);
+        assert_eq!(histogram(&[7, 7]), vec![(7, 7, 2)]);
+    }
+
+    #[test]
+    fn even_median() -> Result<()> {
+        let report = Report::new(&parse("1\n\n2\n\n3\n\n10")?).unwrap();
+        assert_eq!(report.median, 2.5);
+        Ok(())
+    }
+}
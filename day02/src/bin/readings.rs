@@ -0,0 +1,23 @@
+use std::path::Path;
+use std::process::ExitCode;
+
+use anyhow::Result;
+use day02::{Encoding, Rules};
+
+fn main() -> ExitCode {
+    aoc_common::report(run())
+}
+
+/// Takes the input as usual, then optionally a rules file for some other game.
+fn run() -> Result<()> {
+    let text = aoc_common::input::load(2, Some(day02::INPUT))?;
+    let rules = Rules::load(std::env::args().nth(2).as_deref().map(Path::new))?;
+    let encoding = Encoding::standard(&rules);
+
+    println!("Score  Reading");
+    for reading in day02::readings(&text, &rules, &encoding)? {
+        println!("{:>5}  {}", reading.score, reading.column.describe(&rules));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use std::iter;
+use std::path::Path;
+use std::process::ExitCode;
+
+use anyhow::Result;
+use day02::{Follow, Opponent, Rules};
+
+const MATCHES: usize = 1000;
+const SEED: u64 = 2022;
+
+fn main() -> ExitCode {
+    aoc_common::report(run())
+}
+
+/// Takes the input and rules as `day02` does, and plays the guide against each opponent model.
+fn run() -> Result<()> {
+    let text = aoc_common::input::load(2, Some(day02::INPUT))?;
+    let rules = Rules::load(std::env::args().nth(2).as_deref().map(Path::new))?;
+    let guide = day02::parse_with(&text, rules)?;
+    let rules = &guide.rules;
+
+    // Favours the first weapon, as rock is in rock, paper, scissors.
+    let weights = iter::once(2.0)
+        .chain(iter::repeat(1.0))
+        .take(rules.len())
+        .collect();
+    let opponents = [
+        ("uniform", Opponent::Uniform),
+        ("biased", Opponent::biased(rules, weights)?),
+        ("adaptive", Opponent::Adaptive),
+    ];
+
+    println!(
+        "{} matches of {} rounds each, seed {}",
+        MATCHES,
+        guide.hints.len(),
+        SEED
+    );
+    for follow in [Follow::Hints, Follow::Goals] {
+        let ours = day02::our_throws(&guide, follow);
+        for (name, opponent) in &opponents {
+            let scores = day02::simulate(rules, &ours, opponent, MATCHES, SEED);
+            println!("{:?} against {}: {}", follow, name, scores);
+        }
+    }
+
+    Ok(())
+}
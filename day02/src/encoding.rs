@@ -0,0 +1,305 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+
+use aoc_common::parse;
+use itertools::Itertools;
+use thiserror::Error;
+
+use crate::{symbol, symbols, Goal, Hint, Outcome, ParseError, Rules, Throw};
+
+/// The most readings of each kind [`readings`] will score.
+pub const MAX_READINGS: u64 = 10_000;
+
+#[derive(Debug, Error)]
+pub enum ReadingsError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error("there are {count} ways to read our column as {kind}, more than the {max} allowed")]
+    TooMany {
+        kind: &'static str,
+        count: u64,
+        max: u64,
+    },
+}
+
+/// How to read the strategy guide's two columns: the opponent's as throws, and ours as either
+/// throws (for [`Hint`]s) or outcomes (for [`Goal`]s).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Encoding {
+    pub theirs: BTreeMap<char, Throw>,
+    pub ours: BTreeMap<char, Throw>,
+    pub outcomes: BTreeMap<char, Outcome>,
+}
+
+impl Encoding {
+    /// The puzzle's cipher, stretched to any number of weapons: the opponent's column letters
+    /// them from `A`, ours letters them up to `Z` (`X`, `Y` and `Z` for rock, paper and
+    /// scissors, or `V` to `Z` for five weapons), and `X`, `Y` and `Z` are also lose, draw and
+    /// win.
+    pub fn standard(rules: &Rules) -> Encoding {
+        let n = rules.len().min(26) as u8;
+        let letters = |from: u8| {
+            (from..from + n)
+                .map(char::from)
+                .zip(rules.throws())
+                .collect()
+        };
+        Encoding {
+            theirs: letters(b'A'),
+            ours: letters(b'Z' + 1 - n),
+            outcomes: BTreeMap::from([
+                ('X', Outcome::Lose),
+                ('Y', Outcome::Draw),
+                ('Z', Outcome::Win),
+            ]),
+        }
+    }
+
+    /// Every symbol our column may hold, read either way.
+    pub fn our_symbols(&self) -> BTreeSet<char> {
+        self.ours
+            .keys()
+            .chain(self.outcomes.keys())
+            .copied()
+            .collect()
+    }
+}
+
+/// Decodes the symbol `part` of `line` with `map`.
+pub(crate) fn decode<T: Copy>(
+    line: &str,
+    part: &str,
+    map: &BTreeMap<char, T>,
+) -> Result<T, ParseError> {
+    symbol(line, part, expected(map.keys()), |ch| map.get(&ch).copied())
+}
+
+/// Lists `symbols` for an error message, like "A, B or C".
+fn expected<'a>(symbols: impl Iterator<Item = &'a char>) -> String {
+    let symbols: Vec<String> = symbols.map(char::to_string).collect();
+    match symbols.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => "nothing".to_string(),
+    }
+}
+
+/// One way of reading our column.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Column {
+    Throws(BTreeMap<char, Throw>),
+    Outcomes(BTreeMap<char, Outcome>),
+}
+
+impl Column {
+    /// Spells the reading out, like "X = Rock, Y = Paper, Z = Scissors".
+    pub fn describe(&self, rules: &Rules) -> String {
+        match self {
+            Column::Throws(map) => map
+                .iter()
+                .map(|(ch, throw)| format!("{} = {}", ch, throw.name(rules)))
+                .join(", "),
+            Column::Outcomes(map) => map
+                .iter()
+                .map(|(ch, outcome)| format!("{} = {:?}", ch, outcome))
+                .join(", "),
+        }
+    }
+}
+
+/// A reading of our column, and what following the guide that way would score.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Reading {
+    pub column: Column,
+    pub score: i32,
+}
+
+/// Scores the guide under every way of reading our column: each of its symbols as a different
+/// throw, and each as any outcome. The opponent's column is read with `encoding`, which also
+/// gives the symbols our column may hold. Readings are best first.
+///
+/// There are more readings than [`MAX_READINGS`] of either kind once the rules have a handful
+/// of weapons, so that's an error rather than a very long wait.
+pub fn readings(
+    input: &str,
+    rules: &Rules,
+    encoding: &Encoding,
+) -> Result<Vec<Reading>, ReadingsError> {
+    let ours: Vec<char> = encoding.our_symbols().into_iter().collect();
+    let outcomes = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+    let (n, k) = (rules.len() as u64, ours.len() as u64);
+    // n! / (n - k)! ways to give each symbol a different weapon, or none if there are too few.
+    let throw_count = if k > n {
+        0
+    } else {
+        (n - k + 1..=n).fold(1, u64::saturating_mul)
+    };
+    check("throws", throw_count)?;
+
+    check(
+        "outcomes",
+        (outcomes.len() as u64).saturating_pow(ours.len() as u32),
+    )?;
+
+    let as_is: BTreeMap<char, char> = ours.iter().map(|&ch| (ch, ch)).collect();
+    let rounds = parse::lines_with(input, |l| {
+        let (they, we) = symbols(l)?;
+        Ok::<_, ParseError>((decode(l, they, &encoding.theirs)?, decode(l, we, &as_is)?))
+    })?;
+
+    let throws: Vec<Throw> = rules.throws().collect();
+    let by_throw = injections(&ours, &throws).map(|map| Reading {
+        score: rounds
+            .iter()
+            .map(|&(they, we)| Hint { they, we: map[&we] }.score(rules))
+            .sum(),
+        column: Column::Throws(map),
+    });
+    let by_outcome = assignments(&ours, &outcomes).map(|map| Reading {
+        score: rounds
+            .iter()
+            .map(|&(they, we)| {
+                Goal {
+                    they,
+                    outcome: map[&we],
+                }
+                .score(rules)
+            })
+            .sum(),
+        column: Column::Outcomes(map),
+    });
+
+    let mut readings: Vec<Reading> = by_throw.chain(by_outcome).collect();
+    readings.sort_by_key(|r| Reverse(r.score));
+    Ok(readings)
+}
+
+fn check(kind: &'static str, count: u64) -> Result<(), ReadingsError> {
+    if count > MAX_READINGS {
+        return Err(ReadingsError::TooMany {
+            kind,
+            count,
+            max: MAX_READINGS,
+        });
+    }
+    Ok(())
+}
+
+/// Every way of giving each of `symbols` a different one of `values`.
+fn injections<'a, T: Copy>(
+    symbols: &'a [char],
+    values: &'a [T],
+) -> impl Iterator<Item = BTreeMap<char, T>> + 'a {
+    values
+        .iter()
+        .copied()
+        .permutations(symbols.len())
+        .map(move |p| symbols.iter().copied().zip(p).collect())
+}
+
+/// Every way of giving each of `symbols` one of `values`, which may repeat.
+fn assignments<'a, T: Copy>(
+    symbols: &'a [char],
+    values: &'a [T],
+) -> impl Iterator<Item = BTreeMap<char, T>> + 'a {
+    itertools::repeat_n(values.iter().copied(), symbols.len())
+        .multi_cartesian_product()
+        .map(move |p| symbols.iter().copied().zip(p).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_encoded, part_1, ROCK_PAPER_SCISSORS_LIZARD_SPOCK, TEST};
+    use anyhow::Result;
+
+    #[test]
+    fn standard_encoding() -> Result<()> {
+        let rules: Rules = ROCK_PAPER_SCISSORS_LIZARD_SPOCK.parse()?;
+        let encoding = Encoding::standard(&rules);
+        assert_eq!(encoding.theirs[&'E'], rules.throw("Spock").unwrap());
+        assert_eq!(encoding.ours[&'V'], rules.throw("Rock").unwrap());
+        assert_eq!(encoding.outcomes[&'X'], Outcome::Lose);
+        Ok(())
+    }
+
+    #[test]
+    fn custom_encoding() -> Result<()> {
+        let rules = Rules::default();
+        let [rock, paper, scissors] = [0, 1, 2].map(Throw);
+        let encoding = Encoding {
+            theirs: BTreeMap::from([('R', rock), ('P', paper), ('S', scissors)]),
+            ours: BTreeMap::from([('r', rock), ('p', paper), ('s', scissors)]),
+            outcomes: BTreeMap::from([('r', Outcome::Lose), ('p', Outcome::Draw)]),
+        };
+        let guide = parse_encoded("R p", rules.clone(), &encoding)?;
+        assert_eq!(part_1(&guide)?, 8);
+
+        let err = parse_encoded("R s", rules, &encoding).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: expected p or r, found \"s\""
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn every_reading() -> Result<()> {
+        let rules = Rules::default();
+        let readings = readings(TEST, &rules, &Encoding::standard(&rules))?;
+        // Six ways to read our column as throws, and 27 as outcomes.
+        assert_eq!(readings.len(), 6 + 27);
+        assert!(readings.windows(2).all(|w| w[0].score >= w[1].score));
+
+        let puzzle = Column::Throws(Encoding::standard(&rules).ours);
+        let reading = readings.iter().find(|r| r.column == puzzle).unwrap();
+        assert_eq!(reading.score, 15);
+        assert_eq!(
+            reading.column.describe(&rules),
+            "X = Rock, Y = Paper, Z = Scissors"
+        );
+        assert_eq!(readings[0].score, 24);
+        Ok(())
+    }
+
+    #[test]
+    fn lizard_spock_readings() -> Result<()> {
+        let rules: Rules = ROCK_PAPER_SCISSORS_LIZARD_SPOCK.parse()?;
+        let readings = readings("A V\nE Z", &rules, &Encoding::standard(&rules))?;
+        let count = |outcomes: bool| {
+            readings
+                .iter()
+                .filter(|r| matches!(r.column, Column::Outcomes(_)) == outcomes)
+                .count()
+        };
+        // Our column's five symbols, V to Z, as five different weapons or any outcomes.
+        assert_eq!(count(false), 120);
+        assert_eq!(count(true), 3usize.pow(5));
+        Ok(())
+    }
+
+    #[test]
+    fn too_many_readings() {
+        let mut toml = "[outcomes]\nlose = 0\ndraw = 3\nwin = 6\n".to_string();
+        // Each weapon beats the next four, round a circle of nine.
+        for i in 0..9 {
+            let beats = (1..=4).map(|d| format!("\"W{}\"", (i + d) % 9)).join(", ");
+            toml += &format!(
+                "[[weapons]]\nname = \"W{}\"\nscore = 1\nbeats = [{}]\n",
+                i, beats
+            );
+        }
+        let rules: Rules = toml.parse().unwrap();
+        let err = readings("A R", &rules, &Encoding::standard(&rules)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "there are 362880 ways to read our column as throws, more than the 10000 allowed"
+        );
+    }
+
+    #[test]
+    fn expected_symbols() {
+        assert_eq!(expected(['A'].iter()), "A");
+        assert_eq!(expected(['A', 'B', 'C'].iter()), "A, B or C");
+    }
+}
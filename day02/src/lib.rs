@@ -0,0 +1,2742 @@
+use anyhow::Result;
+use aoc_common::parse::{self, Located};
+use thiserror::Error;
+
+mod encoding;
+mod rules;
+mod sim;
+
+use encoding::decode;
+pub use encoding::{readings, Column, Encoding, Reading, ReadingsError, MAX_READINGS};
+pub use rules::{
+    OutcomeScores, Rules, RulesError, Weapon, ROCK_PAPER_SCISSORS, ROCK_PAPER_SCISSORS_LIZARD_SPOCK,
+};
+pub use sim::{our_throws, play, simulate, Distribution, Follow, Opponent, SimError, Weights};
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("line {line}, column {column}: expected {expected}, found {text:?}")]
+    BadSymbol {
+        line: usize,
+        column: usize,
+        text: String,
+        expected: String,
+    },
+    #[error("line {line}, column {column}: expected two symbols, found {text:?}")]
+    MissingSymbol {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+}
+
+impl Located for ParseError {
+    fn below(mut self, lines: usize) -> Self {
+        match &mut self {
+            ParseError::BadSymbol { line, .. } | ParseError::MissingSymbol { line, .. } => {
+                *line += lines
+            }
+        }
+        self
+    }
+}
+
+/// Parses the one-character symbol `part` of `line` with `f`.
+fn symbol<T>(
+    line: &str,
+    part: &str,
+    expected: String,
+    f: impl FnOnce(char) -> Option<T>,
+) -> Result<T, ParseError> {
+    let mut chars = part.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => f(ch),
+        _ => None,
+    }
+    .ok_or_else(|| ParseError::BadSymbol {
+        line: 1,
+        column: parse::column(line, part),
+        text: part.to_string(),
+        expected,
+    })
+}
+
+/// Splits a line into its two symbols.
+fn symbols(s: &str) -> Result<(&str, &str), ParseError> {
+    s.split_once(' ').ok_or_else(|| ParseError::MissingSymbol {
+        line: 1,
+        column: s.len() + 1,
+        text: s.to_string(),
+    })
+}
+
+/// One of the weapons of a game's [`Rules`], numbered from 0 in the order they list them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Throw(pub usize);
+
+impl Throw {
+    /// The score for playing this throw, whatever the outcome.
+    pub fn score(&self, rules: &Rules) -> i32 {
+        rules.weapons[self.0].score
+    }
+
+    pub fn beats(&self, other: &Self, rules: &Rules) -> bool {
+        rules.beats(*self, *other)
+    }
+
+    pub fn name<'a>(&self, rules: &'a Rules) -> &'a str {
+        &rules.weapons[self.0].name
+    }
+}
+
+/// A line of the strategy guide, read as what to throw in reply.
+#[derive(Debug)]
+pub struct Hint {
+    pub they: Throw,
+    pub we: Throw,
+}
+
+impl Hint {
+    pub fn decode(s: &str, encoding: &Encoding) -> Result<Self, ParseError> {
+        let (they, we) = symbols(s)?;
+        Ok(Hint {
+            they: decode(s, they, &encoding.theirs)?,
+            we: decode(s, we, &encoding.ours)?,
+        })
+    }
+
+    /// Our score for the round.
+    pub fn score(&self, rules: &Rules) -> i32 {
+        rules.outcome(self.we, self.they).score(rules) + self.we.score(rules)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn score(&self, rules: &Rules) -> i32 {
+        match self {
+            Outcome::Lose => rules.outcomes.lose,
+            Outcome::Draw => rules.outcomes.draw,
+            Outcome::Win => rules.outcomes.win,
+        }
+    }
+}
+
+/// A line of the strategy guide, read as how the round should end.
+#[derive(Debug)]
+pub struct Goal {
+    pub they: Throw,
+    pub outcome: Outcome,
+}
+
+impl Goal {
+    pub fn decode(s: &str, encoding: &Encoding) -> Result<Self, ParseError> {
+        let (they, outcome) = symbols(s)?;
+        Ok(Goal {
+            they: decode(s, they, &encoding.theirs)?,
+            outcome: decode(s, outcome, &encoding.outcomes)?,
+        })
+    }
+
+    /// What to throw to get the outcome we want.
+    pub fn plan(&self, rules: &Rules) -> Throw {
+        rules.plan(self.they, self.outcome)
+    }
+
+    /// Our score for the round.
+    pub fn score(&self, rules: &Rules) -> i32 {
+        self.outcome.score(rules) + self.plan(rules).score(rules)
+    }
+}
+
+/// The strategy guide, read both ways, and the rules of the game it's for.
+#[derive(Debug)]
+pub struct Guide {
+    pub rules: Rules,
+    pub hints: Vec<Hint>,
+    pub goals: Vec<Goal>,
+}
+
+/// Reads a guide for rock, paper, scissors.
+pub fn parse(input: &str) -> Result<Guide, ParseError> {
+    parse_with(input, Rules::default())
+}
+
+/// Reads a guide for the game `rules` describes, in the [standard](Encoding::standard)
+/// encoding.
+pub fn parse_with(input: &str, rules: Rules) -> Result<Guide, ParseError> {
+    let encoding = Encoding::standard(&rules);
+    parse_encoded(input, rules, &encoding)
+}
+
+/// Reads a guide for the game `rules` describes, written with `encoding`.
+pub fn parse_encoded(input: &str, rules: Rules, encoding: &Encoding) -> Result<Guide, ParseError> {
+    let hints = parse::lines_with(input, |l| Hint::decode(l, encoding))?;
+    let goals = parse::lines_with(input, |l| Goal::decode(l, encoding))?;
+    Ok(Guide {
+        rules,
+        hints,
+        goals,
+    })
+}
+
+pub fn part_1(guide: &Guide) -> Result<i32> {
+    let total_score: i32 = guide.hints.iter().map(|h| h.score(&guide.rules)).sum();
+    Ok(total_score)
+}
+
+pub fn part_2(guide: &Guide) -> Result<i32> {
+    let total_score: i32 = guide.goals.iter().map(|g| g.score(&guide.rules)).sum();
+    Ok(total_score)
+}
+
+pub const INPUT: &str = r#"C Y
+A Z
+B X
+C Y
+B Y
+C X
+C Y
+B X
+B X
+A Z
+C X
+B Y
+B X
+B X
+A Z
+B X
+C Y
+C Y
+C X
+B X
+B X
+C Y
+C Y
+A Z
+C Y
+A X
+B Y
+C Y
+A Z
+B X
+B X
+C Y
+A Y
+A Y
+C Y
+C Z
+C Y
+C X
+C X
+B X
+C Y
+A Y
+C Z
+B X
+C Y
+B Y
+C Y
+A Y
+B X
+B Y
+A X
+A Y
+B X
+B X
+B X
+A Z
+C Y
+B Y
+A X
+A Y
+C X
+C X
+C Y
+B Y
+C X
+B X
+C X
+A Z
+B Y
+C X
+C Y
+B X
+B X
+C Y
+B X
+C Y
+A Z
+B Y
+B X
+B X
+B X
+C Y
+C Y
+A Y
+C X
+C X
+A Y
+C Y
+B X
+A Y
+C X
+C Y
+C X
+C X
+B Z
+C Y
+B X
+C Y
+A Y
+B Y
+B Y
+B X
+A Y
+C Y
+B X
+B Y
+A Y
+B X
+C Y
+C Y
+B Y
+B Y
+C Z
+C Y
+C X
+B X
+C Y
+B X
+C Y
+A Y
+A Y
+A Z
+C Z
+A Y
+B Y
+B X
+C X
+C Y
+C X
+B X
+B X
+A Y
+A Y
+C Y
+C Y
+A Z
+B X
+B X
+B X
+B Y
+B X
+B X
+C Y
+C Y
+C Y
+C X
+B X
+C Y
+B X
+A Z
+C Y
+C X
+A Z
+A Y
+A X
+C Y
+B X
+A Z
+C Y
+A Z
+C Y
+B Y
+A Z
+A Y
+A X
+A Z
+A Z
+C Y
+B X
+C Y
+C Y
+A Z
+B X
+C Y
+B X
+C Y
+A Y
+B X
+A Z
+A Z
+C Y
+A Z
+B Y
+B X
+A Z
+B X
+B X
+B X
+B Y
+C Y
+C Y
+C X
+A Y
+C X
+B X
+C Z
+B Y
+C Y
+A Y
+C X
+C X
+B X
+B X
+B X
+A Y
+B Y
+B X
+B X
+C Y
+A Y
+B X
+B X
+A Z
+C Y
+C Y
+B Y
+B X
+A Y
+B X
+B X
+A Z
+C Y
+A Y
+B X
+A Z
+C Z
+A Z
+B X
+B X
+B Y
+A Z
+C X
+B X
+C Y
+B X
+C Y
+C X
+A Z
+B X
+A Z
+C Y
+B X
+C X
+A Y
+B Y
+C Y
+B X
+B X
+B X
+B Y
+B X
+A X
+C Y
+B Y
+B Y
+C Y
+C Y
+C Y
+A Y
+A Y
+A X
+A Z
+C Y
+A Y
+C X
+C Y
+B Y
+C Y
+C Y
+B X
+C Y
+B X
+C Y
+C Y
+B X
+B Y
+B Y
+B X
+B Y
+B X
+C Y
+C Y
+C X
+C Y
+C X
+C X
+B Y
+A X
+C Y
+A Y
+C Z
+B X
+C Y
+A Y
+A Y
+C Y
+C Y
+B X
+B X
+B Y
+B Y
+C Y
+C X
+A Z
+C Y
+C Y
+C Y
+B Z
+A Z
+B X
+B Y
+C Y
+C Y
+B X
+A Z
+A Z
+B X
+C X
+C Y
+A X
+C Y
+B Y
+B X
+B X
+C Y
+B Y
+A Y
+B X
+B X
+C Z
+B Y
+B X
+C Y
+C Y
+C Y
+B X
+B Y
+C X
+B Y
+C Y
+C Y
+C X
+B X
+C Y
+B X
+C Y
+B X
+A Z
+C Y
+A X
+A Z
+B Z
+B Y
+C Y
+B X
+C Y
+C X
+B X
+B X
+B Y
+A Y
+C Y
+B X
+A Z
+C Y
+B X
+B X
+B X
+C Z
+B Y
+A Y
+B X
+C X
+A Y
+B X
+B X
+B X
+C Y
+C Y
+B X
+C Y
+C Y
+B X
+B Y
+B Y
+C Y
+B Y
+B X
+C X
+A Z
+B Y
+B X
+C Z
+A Y
+B X
+C X
+B X
+B X
+C Z
+B Y
+A X
+C X
+C X
+B X
+C Y
+A Z
+B X
+B X
+B X
+B X
+A Y
+C Y
+B Y
+B X
+A X
+B X
+C X
+C X
+B X
+C Y
+B Z
+A Z
+B X
+B X
+C Y
+B Z
+B X
+A Y
+B X
+A Z
+B X
+C X
+B X
+C X
+B X
+B X
+C Z
+B X
+A Y
+B X
+C Z
+B X
+C Y
+B X
+B Y
+B X
+B X
+C X
+B Y
+B Y
+A Y
+C Y
+B X
+B X
+C Y
+C Y
+C Y
+B X
+A Z
+C Y
+C Y
+A Z
+B X
+A Y
+C X
+B X
+C Y
+A Y
+B Y
+B Y
+A Z
+B X
+B X
+C Y
+B X
+B Y
+B X
+B X
+B X
+C Y
+C Y
+C Y
+C X
+B X
+B X
+C X
+C X
+B X
+A Y
+C Y
+C Y
+C Y
+C Z
+B X
+B X
+A Y
+B X
+A Y
+C X
+B X
+B X
+C Y
+C Y
+C Y
+A Y
+A Y
+A Y
+B X
+C Y
+B Y
+B X
+A Z
+C Y
+B X
+A Y
+B Y
+B Y
+B Y
+B Y
+C X
+A Y
+B X
+A Y
+B X
+C X
+C Y
+B X
+A Z
+C Y
+C Y
+B Y
+B X
+B X
+A Z
+B Y
+B Y
+C Y
+B X
+C X
+C Y
+B X
+B Y
+B X
+B X
+C Y
+B X
+B Y
+B X
+C Y
+B X
+A Y
+B X
+C Z
+B X
+C Y
+C Y
+B X
+B X
+C Y
+C Y
+C Y
+C X
+B Y
+B X
+C X
+B X
+C Z
+B X
+C Y
+A Y
+B X
+A Z
+C Y
+A Y
+B X
+A Z
+C Y
+C Y
+B X
+C Y
+A Y
+B X
+C X
+B Y
+C Y
+B X
+C X
+B X
+B Y
+A Y
+A Y
+A Y
+B X
+B X
+B X
+B X
+B Y
+B X
+B X
+B X
+A Z
+C X
+C Y
+A Z
+C X
+B X
+C Y
+B Y
+C Y
+B X
+C Y
+C Y
+C Y
+C Y
+C Y
+C Z
+B X
+B X
+C Y
+B X
+A Y
+C Y
+C X
+B Y
+C Y
+C Y
+A Y
+A Y
+C Y
+B Y
+C X
+B X
+C Y
+C Y
+B X
+B X
+B X
+A Z
+B Y
+B Y
+A Y
+C X
+B Y
+C Y
+C X
+B X
+B X
+C X
+B X
+C Y
+C Y
+B Y
+C Y
+B X
+B X
+A Z
+B Y
+A Z
+A X
+B Z
+A Y
+C Y
+C Y
+C Y
+B X
+A Z
+A Y
+B Y
+C Y
+B Y
+B X
+B X
+A Y
+C X
+C Y
+B X
+A Y
+A Z
+B X
+B X
+C Y
+B Y
+C Y
+C Y
+B Y
+B Y
+B X
+A Y
+B X
+B X
+B X
+B X
+C Y
+B X
+B Y
+C X
+C Y
+A Y
+B Y
+A Y
+C Y
+C Y
+A Z
+B X
+C Y
+A Z
+A Y
+B X
+B X
+B X
+C Y
+B X
+C Y
+B Y
+B X
+B Y
+A Y
+B X
+C Y
+C Y
+B X
+B X
+C Y
+C Y
+C Y
+B Z
+C Y
+B X
+C Y
+B Y
+C Y
+C X
+B X
+A Y
+A X
+A Z
+A Z
+B Y
+C X
+B X
+B Y
+B X
+B X
+A Z
+B X
+A Y
+C Z
+C Y
+C Y
+C Y
+A Y
+C X
+C Y
+A Z
+C Y
+B X
+A Y
+A Z
+B X
+A Y
+C X
+A Z
+A Y
+B X
+B X
+C Y
+C Y
+C Z
+A Z
+B X
+A Y
+B X
+B X
+B X
+C Y
+A Z
+C Y
+B X
+B Y
+C X
+C Y
+A Z
+A Z
+C Y
+B Y
+C Y
+C Y
+C X
+C Y
+C Y
+B X
+C Y
+B X
+B Y
+C Y
+C Y
+C X
+A Z
+B X
+B X
+C X
+C Y
+C X
+C X
+B X
+B Y
+B Y
+C Z
+A Y
+A Z
+B X
+B X
+C Y
+C Y
+A Y
+C Y
+B Y
+C Y
+B X
+C Y
+B Y
+B X
+C Y
+C Y
+B X
+C Z
+A Y
+B X
+C Y
+A Z
+C X
+C Y
+A Y
+B X
+A Y
+C Y
+B X
+C Y
+B X
+A Y
+C Y
+B Y
+B X
+B X
+C Y
+A Z
+A Z
+C X
+A Y
+B X
+C Y
+B X
+C X
+C Y
+C Y
+C Y
+C Y
+C X
+B Y
+B X
+B X
+C Y
+B Y
+B Y
+B X
+B X
+B X
+B Y
+B X
+B X
+B X
+C Y
+A Y
+A Y
+B X
+C Y
+C Y
+A Y
+C Y
+B Y
+C X
+B X
+B X
+A Y
+C Y
+B X
+B X
+B Y
+C Y
+B X
+C X
+B Y
+A Y
+A Y
+C Y
+C Y
+C Y
+C Y
+C Y
+B X
+C Y
+B Y
+B X
+B Y
+C Y
+B X
+B X
+B X
+B Y
+B X
+B X
+B X
+C Y
+B Y
+C Y
+B X
+B X
+B Y
+C X
+C X
+C Y
+B Y
+B X
+A Z
+B X
+B X
+C X
+C Y
+C Y
+B X
+A Z
+C Z
+C Y
+C Y
+C Y
+C X
+B Y
+B X
+C Y
+C Y
+C Y
+B Y
+B Y
+C Y
+C Y
+B Y
+A Z
+C Y
+A Z
+B X
+C Y
+A Y
+B X
+C Y
+C Y
+B X
+A X
+B X
+B X
+C Y
+C Z
+C Y
+A Y
+C Y
+C Y
+B X
+B X
+B X
+B X
+A Z
+C Y
+C Y
+B Y
+B X
+C Y
+C Y
+C Y
+B X
+A Y
+B X
+C Y
+B X
+B X
+C Y
+C Y
+B Y
+B Y
+C Y
+B Y
+C Y
+A Y
+A Y
+A Y
+C Y
+C Z
+B X
+A X
+C Y
+A Y
+C Y
+A Y
+B X
+B X
+B X
+B X
+B X
+C Z
+B X
+C Y
+C Y
+B Y
+A Z
+A Z
+C Y
+B Y
+C Z
+A Z
+C X
+A Y
+C Y
+C Y
+B X
+C Y
+B Y
+B Y
+C Y
+A X
+C Y
+B X
+A Y
+C X
+B Z
+C X
+B X
+B Y
+A Z
+A X
+B Z
+C Y
+B X
+B Y
+C X
+A Y
+C Y
+C Z
+B Y
+B Y
+B X
+B X
+C Y
+B X
+B Y
+C Y
+C X
+C Z
+C Z
+C Z
+A Y
+B X
+C Y
+C Y
+B X
+A Y
+B X
+A Y
+C X
+C X
+B Y
+A Y
+C Y
+A Y
+C Y
+C Y
+B X
+B X
+A X
+B X
+A Y
+C Y
+C Y
+C X
+A Y
+B X
+B Y
+B X
+B X
+B Z
+B X
+A Y
+C Y
+B X
+B X
+A Y
+A Z
+C Y
+C Y
+A Z
+A Z
+B X
+B X
+C Y
+B X
+B X
+A Y
+A Z
+B Y
+B X
+C Y
+C Y
+B X
+B X
+C X
+C Y
+B X
+B X
+B X
+B X
+B X
+C Y
+C Y
+A Y
+B Y
+B X
+A Z
+C Y
+B Y
+A Z
+C Y
+C Y
+B X
+A Z
+C Y
+A Y
+C X
+B X
+B X
+B Y
+B X
+A Z
+C Y
+B X
+B X
+B X
+B X
+B X
+B X
+C Y
+B X
+B Y
+B Y
+B Y
+C Z
+C Y
+B Y
+A Y
+A Y
+B Z
+B Y
+B Y
+B X
+B Y
+B Y
+C X
+A Z
+C Y
+C Y
+C Y
+B X
+B Y
+B X
+C Y
+C Y
+C Y
+B Y
+A Y
+C Y
+B X
+B X
+C Y
+B Y
+C Y
+B X
+B X
+B Y
+A Y
+C X
+C Z
+B X
+A Y
+C Y
+B X
+C Y
+B X
+C Z
+C Y
+C Y
+C Y
+A Z
+C X
+C Y
+C Y
+A Z
+A Z
+B X
+A Y
+C Y
+C Y
+B X
+A Z
+B X
+C Y
+B Y
+B X
+B X
+C Y
+C Y
+B Y
+C X
+B Y
+C Y
+B Y
+C Y
+B X
+C Y
+B X
+B Y
+B Y
+C X
+C Y
+A Z
+C Y
+C Y
+C Y
+A X
+A Y
+B Y
+C X
+B X
+C Y
+B X
+A Y
+B X
+C Y
+C Y
+C Y
+A Y
+B Y
+B Y
+B X
+A Z
+A Z
+B Z
+C Y
+B Y
+C Y
+B X
+A Z
+C Z
+B X
+C Y
+C Y
+C X
+C Y
+C Y
+C Z
+C Y
+B Y
+B X
+B Y
+B Y
+B X
+A Z
+C Y
+B X
+B X
+B Y
+B Y
+B X
+C X
+C Y
+C X
+B X
+B Y
+B Y
+A Y
+C Y
+B X
+A Y
+C Y
+B X
+B X
+C Y
+C Y
+C X
+B X
+C Z
+B X
+C Y
+A Y
+C X
+B X
+C Y
+B X
+C X
+A Y
+A Y
+A Y
+C Y
+B X
+B X
+B X
+B Y
+A X
+C X
+B X
+C Y
+B X
+B X
+A Y
+B X
+B X
+B X
+B X
+B Y
+B Y
+B X
+C X
+A Z
+C Y
+C Y
+C Y
+B Y
+C Y
+C Y
+B X
+B Y
+C Y
+A Z
+C Y
+B X
+B X
+C Y
+A Z
+C Y
+A Y
+B X
+C Y
+B X
+B Y
+C Y
+C X
+B X
+B Y
+B X
+B X
+B X
+B X
+C Y
+C Y
+A Y
+C Y
+B Z
+A Y
+C Y
+C Y
+B X
+A Y
+C Z
+C Y
+B Y
+C Y
+C Y
+B Y
+C Y
+B X
+B X
+C Y
+B X
+B X
+B Y
+B X
+B X
+B Y
+C X
+C Y
+B X
+A Y
+C X
+B X
+B X
+A X
+B Y
+C Y
+C Y
+B X
+A X
+A Y
+B Y
+B Y
+C Z
+B Y
+B Y
+B X
+B X
+C Y
+A Y
+C Y
+A Y
+B X
+C X
+B Y
+C Y
+B Y
+B Y
+C Y
+A Y
+C Y
+B X
+A X
+C Y
+B X
+B X
+B X
+C Y
+C Y
+C Y
+A Z
+A Z
+C Y
+A Y
+C Y
+B X
+B X
+C X
+B Y
+A Y
+A Z
+A Y
+C X
+B X
+C Y
+B Y
+B X
+A Y
+C Y
+B X
+A Y
+B Y
+C Y
+B Y
+B X
+C Y
+C Y
+B Y
+B X
+C Y
+B Y
+C X
+C Y
+B X
+B X
+C Y
+C Y
+A Y
+C X
+B X
+B X
+B Y
+A Y
+B Y
+B X
+C Y
+C Y
+C X
+B Y
+C Z
+C Y
+C Y
+B Y
+C Z
+B X
+C Y
+A Y
+A Z
+B Y
+B X
+C X
+A Y
+B X
+C Z
+B Y
+C Y
+B X
+B X
+B Y
+C Y
+B Y
+C Y
+C X
+B Y
+B X
+C Z
+B X
+B X
+B X
+B X
+A X
+B X
+C Y
+C X
+B X
+A Y
+C Y
+A Z
+C Y
+C Y
+C Y
+A Z
+B X
+C Y
+A Y
+B X
+B X
+C Y
+A Z
+B X
+C Y
+A Y
+A Z
+C Y
+A Y
+B Y
+B Y
+C Y
+C Y
+C Y
+B X
+C Y
+C Y
+B X
+B X
+A Z
+C X
+C Z
+A Z
+C Y
+B X
+B Y
+B X
+B Y
+C Y
+B X
+B X
+B X
+B X
+B X
+B Y
+C X
+C Y
+C Y
+B X
+B X
+B X
+C Y
+C Y
+C Y
+C Y
+A X
+B X
+B X
+A X
+B Y
+C Y
+C Y
+B Z
+C Y
+C Y
+B X
+B X
+C X
+A Y
+B Y
+B X
+C X
+B X
+B Y
+B X
+C Y
+C Y
+C Y
+B X
+A Z
+B X
+A X
+B X
+A Y
+C Y
+A Y
+C X
+B X
+B Y
+B X
+B X
+C Y
+A Y
+B Y
+A Y
+C Y
+B Y
+B Y
+B X
+A Z
+B X
+B X
+B X
+C Z
+B X
+C Y
+B X
+B X
+C Y
+C X
+B X
+C Y
+B X
+C Y
+C Z
+A Y
+B X
+C Y
+B Y
+C Y
+C Y
+C Y
+B Y
+B Y
+C Y
+A Y
+A Z
+C Y
+C Y
+C Y
+B X
+A Y
+C Z
+B Y
+C Y
+C Y
+B X
+B Y
+A X
+B X
+B Y
+B X
+C Y
+B X
+B Y
+B X
+A Z
+A Z
+B X
+B X
+B X
+C Y
+B X
+C Y
+A Y
+C X
+C Y
+A Z
+A Y
+B X
+B X
+C Y
+B X
+B Y
+A Y
+B X
+B X
+C X
+C X
+B X
+B Y
+C Y
+B X
+B X
+C Y
+B X
+B X
+C X
+B Y
+A Y
+C Y
+B X
+B X
+B Y
+C Y
+A Z
+B X
+C Y
+C Y
+B X
+B X
+A Y
+C Y
+C Y
+B X
+A Y
+B X
+B X
+A Z
+C Y
+B X
+B X
+C Y
+B Y
+C Y
+B X
+A Z
+C Y
+C Y
+B Z
+A Z
+B Z
+B X
+A Z
+C Y
+B Y
+C X
+A Z
+C Y
+A Y
+B X
+C Y
+C Y
+B X
+C Y
+B X
+C Y
+C Y
+B X
+B X
+C Y
+B Y
+A Y
+B X
+C Y
+A Y
+C Y
+C Y
+B X
+C Y
+B X
+B X
+B X
+C Y
+B X
+B Y
+B Y
+A Y
+A Y
+A Z
+B X
+C Y
+B Y
+B X
+B X
+C Y
+B Y
+C Y
+C Y
+A Z
+C Y
+C Y
+B Y
+A Y
+B Y
+C X
+B X
+B X
+B X
+B X
+A Z
+B X
+C X
+A Y
+C Y
+B X
+C Z
+C Y
+B X
+C Y
+B X
+B X
+A Y
+C Z
+B X
+B X
+C Y
+A Z
+A Y
+C X
+B Y
+B Y
+B X
+A Y
+C Y
+B X
+B Y
+B Y
+C Y
+C X
+A Z
+B Y
+C Y
+B X
+C X
+C X
+B Y
+A Z
+B Z
+B X
+B X
+A Z
+B Y
+C Y
+C Y
+B X
+C Z
+C Y
+C Y
+A Y
+C Y
+B Y
+B X
+C Y
+C Y
+A Y
+A Z
+A Z
+B X
+C Y
+B X
+B X
+B X
+B X
+A Z
+B X
+A X
+C Z
+C Y
+B Y
+A Y
+B X
+B Y
+A Z
+C Y
+C Y
+B X
+C Y
+C Y
+C X
+C Y
+B X
+B X
+B X
+C Y
+B Y
+B Y
+C Y
+C Y
+B X
+B X
+B X
+B X
+A Y
+C Y
+B X
+C X
+A Z
+C Y
+B X
+B Y
+B Y
+B Y
+C Z
+B Y
+A Y
+C X
+A Z
+C Y
+C Y
+C X
+A Y
+B Y
+A Y
+B X
+C X
+B X
+C Y
+B Y
+B X
+C Y
+C Y
+B X
+B Y
+B X
+A Z
+A Z
+A Y
+B X
+B X
+B Y
+C X
+A X
+B X
+B Y
+C X
+C Z
+C Y
+A Y
+C Y
+B X
+A Z
+B X
+B X
+A Z
+C X
+A Z
+C Y
+C Y
+A Z
+A Y
+B X
+C Z
+B X
+A Y
+B Y
+A X
+B Y
+B Y
+B Y
+B X
+C Y
+B X
+C Y
+C X
+C X
+A Z
+C Y
+B X
+A Y
+C Y
+A Y
+B X
+C Y
+C Y
+B X
+C Y
+B X
+C Y
+A Y
+A Z
+C Y
+B Z
+C Y
+C Y
+B X
+C Y
+C Y
+C Y
+B X
+A Y
+A Z
+B X
+A X
+B Z
+C Y
+B Z
+C Y
+B Y
+B Y
+C Y
+C Y
+B X
+C Y
+A Z
+B Y
+C Y
+C X
+A Y
+A Y
+B X
+B X
+B X
+A Y
+C Y
+A Y
+B Y
+C Y
+A Y
+A Y
+C Y
+B X
+C Y
+B X
+C Y
+B X
+B X
+C Y
+C X
+A Z
+C Y
+C Y
+A Z
+C Y
+A Y
+A X
+A Z
+B Y
+C Y
+B X
+C Y
+C Y
+C Y
+C Y
+A X
+B X
+C Y
+B Y
+B X
+B X
+A Y
+A Z
+C X
+C Y
+C Z
+C Y
+B Y
+C Y
+B X
+B X
+A X
+B X
+B X
+A Y
+C Y
+B X
+A Z
+C Y
+C Y
+A Z
+C Y
+C X
+B X
+B X
+B X
+C Y
+B Z
+C Y
+B X
+C X
+B X
+C Y
+C Y
+A Y
+C Y
+C Y
+C Z
+A Z
+C Y
+C Y
+C Y
+B X
+C X
+B Z
+C Y
+B X
+B X
+B X
+C Y
+B Y
+B X
+B Y
+A Y
+B Y
+B X
+C X
+C Y
+A Z
+B Y
+C Y
+B X
+C Y
+C X
+B X
+B X
+A Y
+B Y
+B X
+A Y
+B X
+C Y
+C Y
+C Y
+A Z
+A X
+C Y
+C Y
+C Y
+A Z
+B X
+B X
+A Y
+C Y
+C X
+C Y
+B X
+B X
+A Y
+A Y
+B X
+B X
+C Y
+B X
+B X
+A Y
+A Z
+B X
+A Y
+C Y
+C Y
+C Y
+B X
+B X
+C X
+A Y
+C Y
+B Y
+A Y
+C Z
+B X
+C Y
+B Y
+B X
+C Z
+A Y
+C X
+C Y
+C Y
+B X
+C X
+B X
+A Y
+C X
+B Y
+A Y
+A Y
+B X
+C Y
+B X
+C Y
+B Y
+C Y
+C Y
+B X
+B X
+B X
+B X
+B X
+C Y
+A Y
+C Y
+A Z
+A Y
+B X
+C X
+B X
+B Y
+C Z
+B Y
+B X
+C Y
+A X
+C X
+C Y
+B X
+C X
+A Y
+C Z
+C Y
+B X
+A Z
+A Y
+C Y
+C Y
+C Y
+C X
+B X
+A Z
+B Y
+C Y
+C Y
+B X
+B Y
+C X
+C Y
+B X
+B X
+B Z
+C Y
+B X
+B X
+B Z
+B X
+C Y
+C Y
+C Y
+C Z
+C Y
+B Y
+A Z
+A Z
+B X
+B Y
+A Z
+B Y
+B X
+B X
+B Y
+C X
+C Y
+A Y
+C X
+C X
+B Y
+A X
+B Y
+B X
+C Y
+C Y
+B X
+C Y
+A X
+C X
+C X
+B X
+B X
+C X
+B Y
+B Y
+A Y
+B X
+B X
+B X
+C X
+B X
+A Y
+C Y
+B X
+A Z
+B Y
+A Y
+B X
+B X
+C Y
+B Y
+A Z
+B X
+B X
+C Y
+A Y
+A Y
+A Z
+C Y
+A Y
+B X
+A Y
+B X
+C Y
+C Y
+C Y
+B X
+B Y
+B X
+C Y
+A Z
+A Z
+B Y
+B Y
+C X
+C X
+C Y
+B X
+C Y
+B X
+C Y
+C Y
+A Y
+A Y
+A Z
+A Y
+B X
+B X
+B X
+B Y
+C Y
+C X
+C Y
+B X
+B Y
+C Y
+C X
+C Y
+B X
+A Z
+C Y
+C Y
+B X
+A Z
+B X
+A Z
+A X
+A X
+B X
+B X
+C Y
+C X
+B Y
+C Z
+B X
+C Y
+A Z
+B X
+A Y
+C X
+B Y
+C Y
+B Y
+C X
+C Y
+C Y
+B X
+B Y
+C Y
+C X
+C Y
+B Y
+B Y
+B X
+B Z
+C Y
+C Y
+B X
+C Y
+C Y
+C Y
+B Y
+B Y
+C Y
+C Y
+C X
+B Z
+C Y
+C Y
+C Y
+B Y
+C X
+B Y
+A Y
+B X
+B Y
+A Z
+C Y
+B X
+A X
+C X
+B X
+B X
+A Y
+A Y
+A Y
+A Y
+B X
+B X
+A Z
+A Z
+C Y
+B X
+A Z
+B X
+B X
+C Y
+A Y
+B X
+B X
+A Y
+B X
+A X
+C Y
+B X
+B X
+A Z
+C Y
+B Y
+C Y
+A Z
+B X
+C X
+B Y
+C X
+B X
+B X
+C X
+A Y
+B X
+B Y
+B X
+C Y
+B X
+A Y
+B X
+C Y
+B Z
+B X
+A Z
+B X
+A Z
+A Y
+B Z
+B Y
+B X
+B X
+B Z
+B X
+C Y
+B Y
+A Z
+C Y
+B X
+B Y
+A Y
+C Y
+C Y
+B X
+B X
+C Y
+B X
+C Y
+B X
+B X
+A Y
+C Y
+A Y
+A Y
+A Y
+C Y
+C Y
+A Z
+B X
+A Y
+C Y
+B X
+C X
+B Y
+C Z
+C Y
+B X
+C Y
+C Y
+B X
+B X
+B X
+C X
+C X
+B X
+C X
+C X
+C X
+A Z
+B Y
+B X
+B X
+B X
+C Y
+A Z
+B Y
+B X
+B X
+B Y
+A Z
+B X
+C Y
+C Y
+A Z
+C Y
+B X
+A Y
+C Y
+B X
+"#;
+
+#[cfg(test)]
+pub(crate) const TEST: &str = r#"A Y
+B X
+C Z"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_sample() -> Result<()> {
+        assert_eq!(part_1(&parse(TEST)?)?, 15);
+        Ok(())
+    }
+
+    #[test]
+    fn part_2_sample() -> Result<()> {
+        assert_eq!(part_2(&parse(TEST)?)?, 12);
+        Ok(())
+    }
+
+    #[test]
+    fn lizard_spock_guide() -> Result<()> {
+        let rules: Rules = ROCK_PAPER_SCISSORS_LIZARD_SPOCK.parse()?;
+        // Spock beats Rock, Lizard beats Paper, and Scissors loses to Spock.
+        let guide = parse_with("A Z\nB Y\nE X", rules)?;
+        assert_eq!(part_1(&guide)?, (5 + 6) + (4 + 6) + 3);
+        // Spock is the best way to beat Rock, and Scissors the best way to lose to Spock.
+        assert_eq!(part_2(&guide)?, (5 + 6) + (2 + 3) + 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bad_symbols() {
+        let err = parse("A Y\nD X").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected A, B or C, found \"D\""
+        );
+    }
+}
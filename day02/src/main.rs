@@ -1,2662 +1,21 @@
-use anyhow::{Error, Result};
-use std::str::FromStr;
+use std::path::Path;
+use std::process::ExitCode;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum Throw {
-    Rock,
-    Paper,
-    Scissors,
-}
-
-impl FromStr for Throw {
-    type Err = Error;
-
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        Throw::from_char(s.chars().next().unwrap())
-    }
-}
-
-impl Throw {
-    fn score(&self) -> i32 {
-        match self {
-            Throw::Rock => 1,
-            Throw::Paper => 2,
-            Throw::Scissors => 3,
-        }
-    }
-
-    fn beats(&self, other: &Self) -> bool {
-        match self {
-            Throw::Rock => *other == Throw::Scissors,
-            Throw::Paper => *other == Throw::Rock,
-            Throw::Scissors => *other == Throw::Paper,
-        }
-    }
-
-    fn from_char(ch: char) -> std::result::Result<Self, Error> {
-        match ch {
-            'A' => Ok(Throw::Rock),
-            'X' => Ok(Throw::Rock),
-            'B' => Ok(Throw::Paper),
-            'Y' => Ok(Throw::Paper),
-            'C' => Ok(Throw::Scissors),
-            'Z' => Ok(Throw::Scissors),
-            _ => Err(Error::msg("Unmatched")),
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Hint {
-    they: char,
-    we: char,
-}
-
-impl Hint {
-    fn score(&self) -> i32 {
-        let our_throw = Throw::from_char(self.we).unwrap();
-        let their_throw = Throw::from_char(self.they).unwrap();
-
-        if their_throw == our_throw {
-            3 + our_throw.score()
-        } else if our_throw.beats(&their_throw) {
-            6 + our_throw.score()
-        } else {
-            our_throw.score()
-        }
-    }
-}
-
-impl FromStr for Hint {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.split(" ");
-        let they = parts.next().unwrap().chars().next().unwrap();
-        let we = parts.next().unwrap().chars().next().unwrap();
-        Ok(Hint { they, we })
-    }
-}
-
-enum Outcome {
-    LOSE,
-    DRAW,
-    WIN,
-}
-
-impl Outcome {
-    fn from_char(ch: char) -> std::result::Result<Self, Error> {
-        match ch {
-            'X' => Ok(Outcome::LOSE),
-            'Y' => Ok(Outcome::DRAW),
-            'Z' => Ok(Outcome::WIN),
-            _ => Err(Error::msg("Unmatched")),
-        }
-    }
+use anyhow::Result;
+use day02::Rules;
 
-    fn score(&self) -> i32 {
-        match self {
-            Outcome::LOSE => 0,
-            Outcome::DRAW => 3,
-            Outcome::WIN => 6,
-        }
-    }
+fn main() -> ExitCode {
+    aoc_common::report(run())
 }
 
-#[derive(Debug)]
-struct Goal {
-    they: char,
-    outcome: char,
-}
-
-impl Goal {
-    fn plan(&self) -> Throw {
-        let outcome = Outcome::from_char(self.outcome).unwrap();
-        let their_throw = Throw::from_char(self.they).unwrap();
-        match outcome {
-            Outcome::LOSE => match their_throw {
-                Throw::Rock => Throw::Scissors,
-                Throw::Paper => Throw::Rock,
-                Throw::Scissors => Throw::Paper,
-            },
-            Outcome::DRAW => their_throw,
-            Outcome::WIN => match their_throw {
-                Throw::Rock => Throw::Paper,
-                Throw::Paper => Throw::Scissors,
-                Throw::Scissors => Throw::Rock,
-            },
-        }
-    }
+/// Takes the input as usual, then optionally a rules file for some other game.
+fn run() -> Result<()> {
+    let text = aoc_common::input::load(2, Some(day02::INPUT))?;
+    let rules = Rules::load(std::env::args().nth(2).as_deref().map(Path::new))?;
+    let input = day02::parse_with(&text, rules)?;
 
-    fn score(&self) -> i32 {
-        let outcome = Outcome::from_char(self.outcome).unwrap();
-
-        outcome.score() + self.plan().score()
-    }
-}
-
-impl FromStr for Goal {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.split(" ");
-        let they = parts.next().unwrap().chars().next().unwrap();
-        let we = parts.next().unwrap().chars().next().unwrap();
-        Ok(Goal { they, outcome: we })
-    }
-}
-
-fn main() -> Result<()> {
-    let input: Vec<Hint> = INPUT.lines().map(|l| l.parse().unwrap()).collect();
-
-    let total_score: i32 = input.iter().map(|h| h.score()).sum();
-    println!("Part 1: {}", total_score);
-
-    let input2: Vec<Goal> = INPUT.lines().map(|l| l.parse().unwrap()).collect();
-    let total_score2: i32 = input2.iter().map(|h| h.score()).sum();
-    println!("Part 2: {}", total_score2);
+    println!("Part 1: {}", day02::part_1(&input)?);
+    println!("Part 2: {}", day02::part_2(&input)?);
 
     Ok(())
 }
-
-const INPUT: &str = r#"C Y
-A Z
-B X
-C Y
-B Y
-C X
-C Y
-B X
-B X
-A Z
-C X
-B Y
-B X
-B X
-A Z
-B X
-C Y
-C Y
-C X
-B X
-B X
-C Y
-C Y
-A Z
-C Y
-A X
-B Y
-C Y
-A Z
-B X
-B X
-C Y
-A Y
-A Y
-C Y
-C Z
-C Y
-C X
-C X
-B X
-C Y
-A Y
-C Z
-B X
-C Y
-B Y
-C Y
-A Y
-B X
-B Y
-A X
-A Y
-B X
-B X
-B X
-A Z
-C Y
-B Y
-A X
-A Y
-C X
-C X
-C Y
-B Y
-C X
-B X
-C X
-A Z
-B Y
-C X
-C Y
-B X
-B X
-C Y
-B X
-C Y
-A Z
-B Y
-B X
-B X
-B X
-C Y
-C Y
-A Y
-C X
-C X
-A Y
-C Y
-B X
-A Y
-C X
-C Y
-C X
-C X
-B Z
-C Y
-B X
-C Y
-A Y
-B Y
-B Y
-B X
-A Y
-C Y
-B X
-B Y
-A Y
-B X
-C Y
-C Y
-B Y
-B Y
-C Z
-C Y
-C X
-B X
-C Y
-B X
-C Y
-A Y
-A Y
-A Z
-C Z
-A Y
-B Y
-B X
-C X
-C Y
-C X
-B X
-B X
-A Y
-A Y
-C Y
-C Y
-A Z
-B X
-B X
-B X
-B Y
-B X
-B X
-C Y
-C Y
-C Y
-C X
-B X
-C Y
-B X
-A Z
-C Y
-C X
-A Z
-A Y
-A X
-C Y
-B X
-A Z
-C Y
-A Z
-C Y
-B Y
-A Z
-A Y
-A X
-A Z
-A Z
-C Y
-B X
-C Y
-C Y
-A Z
-B X
-C Y
-B X
-C Y
-A Y
-B X
-A Z
-A Z
-C Y
-A Z
-B Y
-B X
-A Z
-B X
-B X
-B X
-B Y
-C Y
-C Y
-C X
-A Y
-C X
-B X
-C Z
-B Y
-C Y
-A Y
-C X
-C X
-B X
-B X
-B X
-A Y
-B Y
-B X
-B X
-C Y
-A Y
-B X
-B X
-A Z
-C Y
-C Y
-B Y
-B X
-A Y
-B X
-B X
-A Z
-C Y
-A Y
-B X
-A Z
-C Z
-A Z
-B X
-B X
-B Y
-A Z
-C X
-B X
-C Y
-B X
-C Y
-C X
-A Z
-B X
-A Z
-C Y
-B X
-C X
-A Y
-B Y
-C Y
-B X
-B X
-B X
-B Y
-B X
-A X
-C Y
-B Y
-B Y
-C Y
-C Y
-C Y
-A Y
-A Y
-A X
-A Z
-C Y
-A Y
-C X
-C Y
-B Y
-C Y
-C Y
-B X
-C Y
-B X
-C Y
-C Y
-B X
-B Y
-B Y
-B X
-B Y
-B X
-C Y
-C Y
-C X
-C Y
-C X
-C X
-B Y
-A X
-C Y
-A Y
-C Z
-B X
-C Y
-A Y
-A Y
-C Y
-C Y
-B X
-B X
-B Y
-B Y
-C Y
-C X
-A Z
-C Y
-C Y
-C Y
-B Z
-A Z
-B X
-B Y
-C Y
-C Y
-B X
-A Z
-A Z
-B X
-C X
-C Y
-A X
-C Y
-B Y
-B X
-B X
-C Y
-B Y
-A Y
-B X
-B X
-C Z
-B Y
-B X
-C Y
-C Y
-C Y
-B X
-B Y
-C X
-B Y
-C Y
-C Y
-C X
-B X
-C Y
-B X
-C Y
-B X
-A Z
-C Y
-A X
-A Z
-B Z
-B Y
-C Y
-B X
-C Y
-C X
-B X
-B X
-B Y
-A Y
-C Y
-B X
-A Z
-C Y
-B X
-B X
-B X
-C Z
-B Y
-A Y
-B X
-C X
-A Y
-B X
-B X
-B X
-C Y
-C Y
-B X
-C Y
-C Y
-B X
-B Y
-B Y
-C Y
-B Y
-B X
-C X
-A Z
-B Y
-B X
-C Z
-A Y
-B X
-C X
-B X
-B X
-C Z
-B Y
-A X
-C X
-C X
-B X
-C Y
-A Z
-B X
-B X
-B X
-B X
-A Y
-C Y
-B Y
-B X
-A X
-B X
-C X
-C X
-B X
-C Y
-B Z
-A Z
-B X
-B X
-C Y
-B Z
-B X
-A Y
-B X
-A Z
-B X
-C X
-B X
-C X
-B X
-B X
-C Z
-B X
-A Y
-B X
-C Z
-B X
-C Y
-B X
-B Y
-B X
-B X
-C X
-B Y
-B Y
-A Y
-C Y
-B X
-B X
-C Y
-C Y
-C Y
-B X
-A Z
-C Y
-C Y
-A Z
-B X
-A Y
-C X
-B X
-C Y
-A Y
-B Y
-B Y
-A Z
-B X
-B X
-C Y
-B X
-B Y
-B X
-B X
-B X
-C Y
-C Y
-C Y
-C X
-B X
-B X
-C X
-C X
-B X
-A Y
-C Y
-C Y
-C Y
-C Z
-B X
-B X
-A Y
-B X
-A Y
-C X
-B X
-B X
-C Y
-C Y
-C Y
-A Y
-A Y
-A Y
-B X
-C Y
-B Y
-B X
-A Z
-C Y
-B X
-A Y
-B Y
-B Y
-B Y
-B Y
-C X
-A Y
-B X
-A Y
-B X
-C X
-C Y
-B X
-A Z
-C Y
-C Y
-B Y
-B X
-B X
-A Z
-B Y
-B Y
-C Y
-B X
-C X
-C Y
-B X
-B Y
-B X
-B X
-C Y
-B X
-B Y
-B X
-C Y
-B X
-A Y
-B X
-C Z
-B X
-C Y
-C Y
-B X
-B X
-C Y
-C Y
-C Y
-C X
-B Y
-B X
-C X
-B X
-C Z
-B X
-C Y
-A Y
-B X
-A Z
-C Y
-A Y
-B X
-A Z
-C Y
-C Y
-B X
-C Y
-A Y
-B X
-C X
-B Y
-C Y
-B X
-C X
-B X
-B Y
-A Y
-A Y
-A Y
-B X
-B X
-B X
-B X
-B Y
-B X
-B X
-B X
-A Z
-C X
-C Y
-A Z
-C X
-B X
-C Y
-B Y
-C Y
-B X
-C Y
-C Y
-C Y
-C Y
-C Y
-C Z
-B X
-B X
-C Y
-B X
-A Y
-C Y
-C X
-B Y
-C Y
-C Y
-A Y
-A Y
-C Y
-B Y
-C X
-B X
-C Y
-C Y
-B X
-B X
-B X
-A Z
-B Y
-B Y
-A Y
-C X
-B Y
-C Y
-C X
-B X
-B X
-C X
-B X
-C Y
-C Y
-B Y
-C Y
-B X
-B X
-A Z
-B Y
-A Z
-A X
-B Z
-A Y
-C Y
-C Y
-C Y
-B X
-A Z
-A Y
-B Y
-C Y
-B Y
-B X
-B X
-A Y
-C X
-C Y
-B X
-A Y
-A Z
-B X
-B X
-C Y
-B Y
-C Y
-C Y
-B Y
-B Y
-B X
-A Y
-B X
-B X
-B X
-B X
-C Y
-B X
-B Y
-C X
-C Y
-A Y
-B Y
-A Y
-C Y
-C Y
-A Z
-B X
-C Y
-A Z
-A Y
-B X
-B X
-B X
-C Y
-B X
-C Y
-B Y
-B X
-B Y
-A Y
-B X
-C Y
-C Y
-B X
-B X
-C Y
-C Y
-C Y
-B Z
-C Y
-B X
-C Y
-B Y
-C Y
-C X
-B X
-A Y
-A X
-A Z
-A Z
-B Y
-C X
-B X
-B Y
-B X
-B X
-A Z
-B X
-A Y
-C Z
-C Y
-C Y
-C Y
-A Y
-C X
-C Y
-A Z
-C Y
-B X
-A Y
-A Z
-B X
-A Y
-C X
-A Z
-A Y
-B X
-B X
-C Y
-C Y
-C Z
-A Z
-B X
-A Y
-B X
-B X
-B X
-C Y
-A Z
-C Y
-B X
-B Y
-C X
-C Y
-A Z
-A Z
-C Y
-B Y
-C Y
-C Y
-C X
-C Y
-C Y
-B X
-C Y
-B X
-B Y
-C Y
-C Y
-C X
-A Z
-B X
-B X
-C X
-C Y
-C X
-C X
-B X
-B Y
-B Y
-C Z
-A Y
-A Z
-B X
-B X
-C Y
-C Y
-A Y
-C Y
-B Y
-C Y
-B X
-C Y
-B Y
-B X
-C Y
-C Y
-B X
-C Z
-A Y
-B X
-C Y
-A Z
-C X
-C Y
-A Y
-B X
-A Y
-C Y
-B X
-C Y
-B X
-A Y
-C Y
-B Y
-B X
-B X
-C Y
-A Z
-A Z
-C X
-A Y
-B X
-C Y
-B X
-C X
-C Y
-C Y
-C Y
-C Y
-C X
-B Y
-B X
-B X
-C Y
-B Y
-B Y
-B X
-B X
-B X
-B Y
-B X
-B X
-B X
-C Y
-A Y
-A Y
-B X
-C Y
-C Y
-A Y
-C Y
-B Y
-C X
-B X
-B X
-A Y
-C Y
-B X
-B X
-B Y
-C Y
-B X
-C X
-B Y
-A Y
-A Y
-C Y
-C Y
-C Y
-C Y
-C Y
-B X
-C Y
-B Y
-B X
-B Y
-C Y
-B X
-B X
-B X
-B Y
-B X
-B X
-B X
-C Y
-B Y
-C Y
-B X
-B X
-B Y
-C X
-C X
-C Y
-B Y
-B X
-A Z
-B X
-B X
-C X
-C Y
-C Y
-B X
-A Z
-C Z
-C Y
-C Y
-C Y
-C X
-B Y
-B X
-C Y
-C Y
-C Y
-B Y
-B Y
-C Y
-C Y
-B Y
-A Z
-C Y
-A Z
-B X
-C Y
-A Y
-B X
-C Y
-C Y
-B X
-A X
-B X
-B X
-C Y
-C Z
-C Y
-A Y
-C Y
-C Y
-B X
-B X
-B X
-B X
-A Z
-C Y
-C Y
-B Y
-B X
-C Y
-C Y
-C Y
-B X
-A Y
-B X
-C Y
-B X
-B X
-C Y
-C Y
-B Y
-B Y
-C Y
-B Y
-C Y
-A Y
-A Y
-A Y
-C Y
-C Z
-B X
-A X
-C Y
-A Y
-C Y
-A Y
-B X
-B X
-B X
-B X
-B X
-C Z
-B X
-C Y
-C Y
-B Y
-A Z
-A Z
-C Y
-B Y
-C Z
-A Z
-C X
-A Y
-C Y
-C Y
-B X
-C Y
-B Y
-B Y
-C Y
-A X
-C Y
-B X
-A Y
-C X
-B Z
-C X
-B X
-B Y
-A Z
-A X
-B Z
-C Y
-B X
-B Y
-C X
-A Y
-C Y
-C Z
-B Y
-B Y
-B X
-B X
-C Y
-B X
-B Y
-C Y
-C X
-C Z
-C Z
-C Z
-A Y
-B X
-C Y
-C Y
-B X
-A Y
-B X
-A Y
-C X
-C X
-B Y
-A Y
-C Y
-A Y
-C Y
-C Y
-B X
-B X
-A X
-B X
-A Y
-C Y
-C Y
-C X
-A Y
-B X
-B Y
-B X
-B X
-B Z
-B X
-A Y
-C Y
-B X
-B X
-A Y
-A Z
-C Y
-C Y
-A Z
-A Z
-B X
-B X
-C Y
-B X
-B X
-A Y
-A Z
-B Y
-B X
-C Y
-C Y
-B X
-B X
-C X
-C Y
-B X
-B X
-B X
-B X
-B X
-C Y
-C Y
-A Y
-B Y
-B X
-A Z
-C Y
-B Y
-A Z
-C Y
-C Y
-B X
-A Z
-C Y
-A Y
-C X
-B X
-B X
-B Y
-B X
-A Z
-C Y
-B X
-B X
-B X
-B X
-B X
-B X
-C Y
-B X
-B Y
-B Y
-B Y
-C Z
-C Y
-B Y
-A Y
-A Y
-B Z
-B Y
-B Y
-B X
-B Y
-B Y
-C X
-A Z
-C Y
-C Y
-C Y
-B X
-B Y
-B X
-C Y
-C Y
-C Y
-B Y
-A Y
-C Y
-B X
-B X
-C Y
-B Y
-C Y
-B X
-B X
-B Y
-A Y
-C X
-C Z
-B X
-A Y
-C Y
-B X
-C Y
-B X
-C Z
-C Y
-C Y
-C Y
-A Z
-C X
-C Y
-C Y
-A Z
-A Z
-B X
-A Y
-C Y
-C Y
-B X
-A Z
-B X
-C Y
-B Y
-B X
-B X
-C Y
-C Y
-B Y
-C X
-B Y
-C Y
-B Y
-C Y
-B X
-C Y
-B X
-B Y
-B Y
-C X
-C Y
-A Z
-C Y
-C Y
-C Y
-A X
-A Y
-B Y
-C X
-B X
-C Y
-B X
-A Y
-B X
-C Y
-C Y
-C Y
-A Y
-B Y
-B Y
-B X
-A Z
-A Z
-B Z
-C Y
-B Y
-C Y
-B X
-A Z
-C Z
-B X
-C Y
-C Y
-C X
-C Y
-C Y
-C Z
-C Y
-B Y
-B X
-B Y
-B Y
-B X
-A Z
-C Y
-B X
-B X
-B Y
-B Y
-B X
-C X
-C Y
-C X
-B X
-B Y
-B Y
-A Y
-C Y
-B X
-A Y
-C Y
-B X
-B X
-C Y
-C Y
-C X
-B X
-C Z
-B X
-C Y
-A Y
-C X
-B X
-C Y
-B X
-C X
-A Y
-A Y
-A Y
-C Y
-B X
-B X
-B X
-B Y
-A X
-C X
-B X
-C Y
-B X
-B X
-A Y
-B X
-B X
-B X
-B X
-B Y
-B Y
-B X
-C X
-A Z
-C Y
-C Y
-C Y
-B Y
-C Y
-C Y
-B X
-B Y
-C Y
-A Z
-C Y
-B X
-B X
-C Y
-A Z
-C Y
-A Y
-B X
-C Y
-B X
-B Y
-C Y
-C X
-B X
-B Y
-B X
-B X
-B X
-B X
-C Y
-C Y
-A Y
-C Y
-B Z
-A Y
-C Y
-C Y
-B X
-A Y
-C Z
-C Y
-B Y
-C Y
-C Y
-B Y
-C Y
-B X
-B X
-C Y
-B X
-B X
-B Y
-B X
-B X
-B Y
-C X
-C Y
-B X
-A Y
-C X
-B X
-B X
-A X
-B Y
-C Y
-C Y
-B X
-A X
-A Y
-B Y
-B Y
-C Z
-B Y
-B Y
-B X
-B X
-C Y
-A Y
-C Y
-A Y
-B X
-C X
-B Y
-C Y
-B Y
-B Y
-C Y
-A Y
-C Y
-B X
-A X
-C Y
-B X
-B X
-B X
-C Y
-C Y
-C Y
-A Z
-A Z
-C Y
-A Y
-C Y
-B X
-B X
-C X
-B Y
-A Y
-A Z
-A Y
-C X
-B X
-C Y
-B Y
-B X
-A Y
-C Y
-B X
-A Y
-B Y
-C Y
-B Y
-B X
-C Y
-C Y
-B Y
-B X
-C Y
-B Y
-C X
-C Y
-B X
-B X
-C Y
-C Y
-A Y
-C X
-B X
-B X
-B Y
-A Y
-B Y
-B X
-C Y
-C Y
-C X
-B Y
-C Z
-C Y
-C Y
-B Y
-C Z
-B X
-C Y
-A Y
-A Z
-B Y
-B X
-C X
-A Y
-B X
-C Z
-B Y
-C Y
-B X
-B X
-B Y
-C Y
-B Y
-C Y
-C X
-B Y
-B X
-C Z
-B X
-B X
-B X
-B X
-A X
-B X
-C Y
-C X
-B X
-A Y
-C Y
-A Z
-C Y
-C Y
-C Y
-A Z
-B X
-C Y
-A Y
-B X
-B X
-C Y
-A Z
-B X
-C Y
-A Y
-A Z
-C Y
-A Y
-B Y
-B Y
-C Y
-C Y
-C Y
-B X
-C Y
-C Y
-B X
-B X
-A Z
-C X
-C Z
-A Z
-C Y
-B X
-B Y
-B X
-B Y
-C Y
-B X
-B X
-B X
-B X
-B X
-B Y
-C X
-C Y
-C Y
-B X
-B X
-B X
-C Y
-C Y
-C Y
-C Y
-A X
-B X
-B X
-A X
-B Y
-C Y
-C Y
-B Z
-C Y
-C Y
-B X
-B X
-C X
-A Y
-B Y
-B X
-C X
-B X
-B Y
-B X
-C Y
-C Y
-C Y
-B X
-A Z
-B X
-A X
-B X
-A Y
-C Y
-A Y
-C X
-B X
-B Y
-B X
-B X
-C Y
-A Y
-B Y
-A Y
-C Y
-B Y
-B Y
-B X
-A Z
-B X
-B X
-B X
-C Z
-B X
-C Y
-B X
-B X
-C Y
-C X
-B X
-C Y
-B X
-C Y
-C Z
-A Y
-B X
-C Y
-B Y
-C Y
-C Y
-C Y
-B Y
-B Y
-C Y
-A Y
-A Z
-C Y
-C Y
-C Y
-B X
-A Y
-C Z
-B Y
-C Y
-C Y
-B X
-B Y
-A X
-B X
-B Y
-B X
-C Y
-B X
-B Y
-B X
-A Z
-A Z
-B X
-B X
-B X
-C Y
-B X
-C Y
-A Y
-C X
-C Y
-A Z
-A Y
-B X
-B X
-C Y
-B X
-B Y
-A Y
-B X
-B X
-C X
-C X
-B X
-B Y
-C Y
-B X
-B X
-C Y
-B X
-B X
-C X
-B Y
-A Y
-C Y
-B X
-B X
-B Y
-C Y
-A Z
-B X
-C Y
-C Y
-B X
-B X
-A Y
-C Y
-C Y
-B X
-A Y
-B X
-B X
-A Z
-C Y
-B X
-B X
-C Y
-B Y
-C Y
-B X
-A Z
-C Y
-C Y
-B Z
-A Z
-B Z
-B X
-A Z
-C Y
-B Y
-C X
-A Z
-C Y
-A Y
-B X
-C Y
-C Y
-B X
-C Y
-B X
-C Y
-C Y
-B X
-B X
-C Y
-B Y
-A Y
-B X
-C Y
-A Y
-C Y
-C Y
-B X
-C Y
-B X
-B X
-B X
-C Y
-B X
-B Y
-B Y
-A Y
-A Y
-A Z
-B X
-C Y
-B Y
-B X
-B X
-C Y
-B Y
-C Y
-C Y
-A Z
-C Y
-C Y
-B Y
-A Y
-B Y
-C X
-B X
-B X
-B X
-B X
-A Z
-B X
-C X
-A Y
-C Y
-B X
-C Z
-C Y
-B X
-C Y
-B X
-B X
-A Y
-C Z
-B X
-B X
-C Y
-A Z
-A Y
-C X
-B Y
-B Y
-B X
-A Y
-C Y
-B X
-B Y
-B Y
-C Y
-C X
-A Z
-B Y
-C Y
-B X
-C X
-C X
-B Y
-A Z
-B Z
-B X
-B X
-A Z
-B Y
-C Y
-C Y
-B X
-C Z
-C Y
-C Y
-A Y
-C Y
-B Y
-B X
-C Y
-C Y
-A Y
-A Z
-A Z
-B X
-C Y
-B X
-B X
-B X
-B X
-A Z
-B X
-A X
-C Z
-C Y
-B Y
-A Y
-B X
-B Y
-A Z
-C Y
-C Y
-B X
-C Y
-C Y
-C X
-C Y
-B X
-B X
-B X
-C Y
-B Y
-B Y
-C Y
-C Y
-B X
-B X
-B X
-B X
-A Y
-C Y
-B X
-C X
-A Z
-C Y
-B X
-B Y
-B Y
-B Y
-C Z
-B Y
-A Y
-C X
-A Z
-C Y
-C Y
-C X
-A Y
-B Y
-A Y
-B X
-C X
-B X
-C Y
-B Y
-B X
-C Y
-C Y
-B X
-B Y
-B X
-A Z
-A Z
-A Y
-B X
-B X
-B Y
-C X
-A X
-B X
-B Y
-C X
-C Z
-C Y
-A Y
-C Y
-B X
-A Z
-B X
-B X
-A Z
-C X
-A Z
-C Y
-C Y
-A Z
-A Y
-B X
-C Z
-B X
-A Y
-B Y
-A X
-B Y
-B Y
-B Y
-B X
-C Y
-B X
-C Y
-C X
-C X
-A Z
-C Y
-B X
-A Y
-C Y
-A Y
-B X
-C Y
-C Y
-B X
-C Y
-B X
-C Y
-A Y
-A Z
-C Y
-B Z
-C Y
-C Y
-B X
-C Y
-C Y
-C Y
-B X
-A Y
-A Z
-B X
-A X
-B Z
-C Y
-B Z
-C Y
-B Y
-B Y
-C Y
-C Y
-B X
-C Y
-A Z
-B Y
-C Y
-C X
-A Y
-A Y
-B X
-B X
-B X
-A Y
-C Y
-A Y
-B Y
-C Y
-A Y
-A Y
-C Y
-B X
-C Y
-B X
-C Y
-B X
-B X
-C Y
-C X
-A Z
-C Y
-C Y
-A Z
-C Y
-A Y
-A X
-A Z
-B Y
-C Y
-B X
-C Y
-C Y
-C Y
-C Y
-A X
-B X
-C Y
-B Y
-B X
-B X
-A Y
-A Z
-C X
-C Y
-C Z
-C Y
-B Y
-C Y
-B X
-B X
-A X
-B X
-B X
-A Y
-C Y
-B X
-A Z
-C Y
-C Y
-A Z
-C Y
-C X
-B X
-B X
-B X
-C Y
-B Z
-C Y
-B X
-C X
-B X
-C Y
-C Y
-A Y
-C Y
-C Y
-C Z
-A Z
-C Y
-C Y
-C Y
-B X
-C X
-B Z
-C Y
-B X
-B X
-B X
-C Y
-B Y
-B X
-B Y
-A Y
-B Y
-B X
-C X
-C Y
-A Z
-B Y
-C Y
-B X
-C Y
-C X
-B X
-B X
-A Y
-B Y
-B X
-A Y
-B X
-C Y
-C Y
-C Y
-A Z
-A X
-C Y
-C Y
-C Y
-A Z
-B X
-B X
-A Y
-C Y
-C X
-C Y
-B X
-B X
-A Y
-A Y
-B X
-B X
-C Y
-B X
-B X
-A Y
-A Z
-B X
-A Y
-C Y
-C Y
-C Y
-B X
-B X
-C X
-A Y
-C Y
-B Y
-A Y
-C Z
-B X
-C Y
-B Y
-B X
-C Z
-A Y
-C X
-C Y
-C Y
-B X
-C X
-B X
-A Y
-C X
-B Y
-A Y
-A Y
-B X
-C Y
-B X
-C Y
-B Y
-C Y
-C Y
-B X
-B X
-B X
-B X
-B X
-C Y
-A Y
-C Y
-A Z
-A Y
-B X
-C X
-B X
-B Y
-C Z
-B Y
-B X
-C Y
-A X
-C X
-C Y
-B X
-C X
-A Y
-C Z
-C Y
-B X
-A Z
-A Y
-C Y
-C Y
-C Y
-C X
-B X
-A Z
-B Y
-C Y
-C Y
-B X
-B Y
-C X
-C Y
-B X
-B X
-B Z
-C Y
-B X
-B X
-B Z
-B X
-C Y
-C Y
-C Y
-C Z
-C Y
-B Y
-A Z
-A Z
-B X
-B Y
-A Z
-B Y
-B X
-B X
-B Y
-C X
-C Y
-A Y
-C X
-C X
-B Y
-A X
-B Y
-B X
-C Y
-C Y
-B X
-C Y
-A X
-C X
-C X
-B X
-B X
-C X
-B Y
-B Y
-A Y
-B X
-B X
-B X
-C X
-B X
-A Y
-C Y
-B X
-A Z
-B Y
-A Y
-B X
-B X
-C Y
-B Y
-A Z
-B X
-B X
-C Y
-A Y
-A Y
-A Z
-C Y
-A Y
-B X
-A Y
-B X
-C Y
-C Y
-C Y
-B X
-B Y
-B X
-C Y
-A Z
-A Z
-B Y
-B Y
-C X
-C X
-C Y
-B X
-C Y
-B X
-C Y
-C Y
-A Y
-A Y
-A Z
-A Y
-B X
-B X
-B X
-B Y
-C Y
-C X
-C Y
-B X
-B Y
-C Y
-C X
-C Y
-B X
-A Z
-C Y
-C Y
-B X
-A Z
-B X
-A Z
-A X
-A X
-B X
-B X
-C Y
-C X
-B Y
-C Z
-B X
-C Y
-A Z
-B X
-A Y
-C X
-B Y
-C Y
-B Y
-C X
-C Y
-C Y
-B X
-B Y
-C Y
-C X
-C Y
-B Y
-B Y
-B X
-B Z
-C Y
-C Y
-B X
-C Y
-C Y
-C Y
-B Y
-B Y
-C Y
-C Y
-C X
-B Z
-C Y
-C Y
-C Y
-B Y
-C X
-B Y
-A Y
-B X
-B Y
-A Z
-C Y
-B X
-A X
-C X
-B X
-B X
-A Y
-A Y
-A Y
-A Y
-B X
-B X
-A Z
-A Z
-C Y
-B X
-A Z
-B X
-B X
-C Y
-A Y
-B X
-B X
-A Y
-B X
-A X
-C Y
-B X
-B X
-A Z
-C Y
-B Y
-C Y
-A Z
-B X
-C X
-B Y
-C X
-B X
-B X
-C X
-A Y
-B X
-B Y
-B X
-C Y
-B X
-A Y
-B X
-C Y
-B Z
-B X
-A Z
-B X
-A Z
-A Y
-B Z
-B Y
-B X
-B X
-B Z
-B X
-C Y
-B Y
-A Z
-C Y
-B X
-B Y
-A Y
-C Y
-C Y
-B X
-B X
-C Y
-B X
-C Y
-B X
-B X
-A Y
-C Y
-A Y
-A Y
-A Y
-C Y
-C Y
-A Z
-B X
-A Y
-C Y
-B X
-C X
-B Y
-C Z
-C Y
-B X
-C Y
-C Y
-B X
-B X
-B X
-C X
-C X
-B X
-C X
-C X
-C X
-A Z
-B Y
-B X
-B X
-B X
-C Y
-A Z
-B Y
-B X
-B X
-B Y
-A Z
-B X
-C Y
-C Y
-A Z
-C Y
-B X
-A Y
-C Y
-B X
-"#;
@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::{Outcome, Throw};
+
+/// Rock, paper, scissors, as the puzzle plays it.
+pub const ROCK_PAPER_SCISSORS: &str = include_str!("../rules/rock-paper-scissors.toml");
+
+/// Rock, paper, scissors, lizard, Spock.
+pub const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str =
+    include_str!("../rules/rock-paper-scissors-lizard-spock.toml");
+
+#[derive(Debug, Error)]
+pub enum RulesError {
+    #[error(transparent)]
+    Toml(#[from] toml::de::Error),
+    #[error("expected at least two weapons, found {0}")]
+    TooFewWeapons(usize),
+    #[error("{0} is listed more than once")]
+    DuplicateWeapon(String),
+    #[error("{weapon} beats {name}, which isn't one of the weapons")]
+    UnknownWeapon { weapon: String, name: String },
+    #[error("{0} beats itself")]
+    BeatsItself(String),
+    #[error("{0} and {1} beat each other")]
+    BeatEachOther(String, String),
+    #[error("neither {0} nor {1} beats the other")]
+    Undecided(String, String),
+    #[error("{0} doesn't beat anything")]
+    BeatsNothing(String),
+    #[error("nothing beats {0}")]
+    Unbeaten(String),
+}
+
+/// The points each outcome is worth, whatever was thrown.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OutcomeScores {
+    pub lose: i32,
+    pub draw: i32,
+    pub win: i32,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Weapon {
+    pub name: String,
+    /// The score for throwing it, whatever the outcome.
+    pub score: i32,
+}
+
+/// A cyclic hand game: its weapons, which of them beats which, and what everything scores.
+///
+/// Rules are read from TOML, with a table per weapon in the order they're numbered:
+///
+/// ```toml
+/// [outcomes]
+/// lose = 0
+/// draw = 3
+/// win = 6
+///
+/// [[weapons]]
+/// name = "Rock"
+/// score = 1
+/// beats = ["Scissors"]
+/// ```
+///
+/// Of any two different weapons, exactly one must beat the other, and every weapon must beat
+/// something and be beaten by something, so that any outcome can be planned for.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Rules {
+    pub weapons: Vec<Weapon>,
+    pub outcomes: OutcomeScores,
+    /// `beats[a][b]` if weapon `a` beats weapon `b`.
+    beats: Vec<Vec<bool>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    outcomes: OutcomeScores,
+    weapons: Vec<WeaponConfig>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WeaponConfig {
+    name: String,
+    score: i32,
+    beats: Vec<String>,
+}
+
+impl Rules {
+    /// The rules in the TOML file at `path`, or rock, paper, scissors if there's no file.
+    pub fn load(path: Option<&Path>) -> Result<Rules> {
+        let Some(path) = path else {
+            return Ok(Rules::default());
+        };
+        let reading = || format!("Reading {}", path.display());
+        let text = fs::read_to_string(path).with_context(reading)?;
+        text.parse().with_context(reading)
+    }
+
+    pub fn len(&self) -> usize {
+        self.weapons.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.weapons.is_empty()
+    }
+
+    /// Every weapon, in order.
+    pub fn throws(&self) -> impl Iterator<Item = Throw> {
+        (0..self.len()).map(Throw)
+    }
+
+    /// The weapon called `name`, if there is one.
+    pub fn throw(&self, name: &str) -> Option<Throw> {
+        self.weapons.iter().position(|w| w.name == name).map(Throw)
+    }
+
+    pub fn beats(&self, a: Throw, b: Throw) -> bool {
+        self.beats[a.0][b.0]
+    }
+
+    /// How a round ends for whoever throws `we` against `they`.
+    pub fn outcome(&self, we: Throw, they: Throw) -> Outcome {
+        if we == they {
+            Outcome::Draw
+        } else if self.beats(we, they) {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    /// What to throw against `they` to get `outcome`. Where several weapons would do, it's the
+    /// best-scoring one, or the first of those.
+    pub fn plan(&self, they: Throw, outcome: Outcome) -> Throw {
+        self.throws()
+            .filter(|&we| self.outcome(we, they) == outcome)
+            .max_by_key(|&we| (we.score(self), std::cmp::Reverse(we)))
+            .expect("validated rules can plan any outcome")
+    }
+}
+
+impl Default for Rules {
+    /// Rock, paper, scissors.
+    fn default() -> Self {
+        ROCK_PAPER_SCISSORS
+            .parse()
+            .expect("the built-in rules are valid")
+    }
+}
+
+impl FromStr for Rules {
+    type Err = RulesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let config: Config = toml::from_str(s)?;
+        // No weapon could beat anything, and there'd be nothing to throw.
+        if config.weapons.len() < 2 {
+            return Err(RulesError::TooFewWeapons(config.weapons.len()));
+        }
+
+        let mut index = HashMap::new();
+        for (i, w) in config.weapons.iter().enumerate() {
+            if index.insert(w.name.as_str(), i).is_some() {
+                return Err(RulesError::DuplicateWeapon(w.name.clone()));
+            }
+        }
+
+        let n = config.weapons.len();
+        let mut beats = vec![vec![false; n]; n];
+        for (a, w) in config.weapons.iter().enumerate() {
+            for name in &w.beats {
+                let b = *index
+                    .get(name.as_str())
+                    .ok_or_else(|| RulesError::UnknownWeapon {
+                        weapon: w.name.clone(),
+                        name: name.clone(),
+                    })?;
+                if a == b {
+                    return Err(RulesError::BeatsItself(w.name.clone()));
+                }
+                beats[a][b] = true;
+            }
+        }
+
+        let name = |i: usize| config.weapons[i].name.clone();
+        for (a, b) in (0..n).flat_map(|a| (a + 1..n).map(move |b| (a, b))) {
+            match (beats[a][b], beats[b][a]) {
+                (true, true) => return Err(RulesError::BeatEachOther(name(a), name(b))),
+                (false, false) => return Err(RulesError::Undecided(name(a), name(b))),
+                _ => {}
+            }
+        }
+        for a in 0..n {
+            if !beats[a].contains(&true) {
+                return Err(RulesError::BeatsNothing(name(a)));
+            }
+            if !beats.iter().any(|row| row[a]) {
+                return Err(RulesError::Unbeaten(name(a)));
+            }
+        }
+
+        Ok(Rules {
+            weapons: config
+                .weapons
+                .into_iter()
+                .map(|w| Weapon {
+                    name: w.name,
+                    score: w.score,
+                })
+                .collect(),
+            outcomes: config.outcomes,
+            beats,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rock_paper_scissors() {
+        let rules = Rules::default();
+        let [rock, paper, scissors] = [0, 1, 2].map(Throw);
+        assert_eq!(rules.throw("Paper"), Some(paper));
+        assert!(rules.beats(rock, scissors));
+        assert!(!rules.beats(scissors, rock));
+        assert_eq!(rules.outcome(paper, rock), Outcome::Win);
+        assert_eq!(rules.plan(rock, Outcome::Lose), scissors);
+        assert_eq!(rules.plan(rock, Outcome::Draw), rock);
+    }
+
+    #[test]
+    fn lizard_spock() -> Result<(), RulesError> {
+        let rules: Rules = ROCK_PAPER_SCISSORS_LIZARD_SPOCK.parse()?;
+        let spock = rules.throw("Spock").unwrap();
+        let lizard = rules.throw("Lizard").unwrap();
+        assert!(rules.beats(lizard, spock));
+        // Paper and Lizard both beat Spock, and Lizard scores more.
+        assert_eq!(rules.plan(spock, Outcome::Win), lizard);
+        assert_eq!(
+            rules.plan(spock, Outcome::Lose),
+            rules.throw("Scissors").unwrap()
+        );
+
+        Ok(())
+    }
+
+    fn rules(weapons: &[(&str, &str)]) -> String {
+        let mut s = "[outcomes]\nlose = 0\ndraw = 3\nwin = 6\n".to_string();
+        for (name, beats) in weapons {
+            s += &format!(
+                "[[weapons]]\nname = \"{}\"\nscore = 1\nbeats = [{}]\n",
+                name, beats
+            );
+        }
+        s
+    }
+
+    #[test]
+    fn rejects_bad_rules() {
+        let error =
+            |weapons: &[(&str, &str)]| rules(weapons).parse::<Rules>().unwrap_err().to_string();
+        assert_eq!(
+            error(&[("A", "\"B\""), ("B", "\"C\""), ("C", "\"D\"")]),
+            "C beats D, which isn't one of the weapons"
+        );
+        assert_eq!(
+            error(&[("A", "\"B\""), ("B", "\"A\"")]),
+            "A and B beat each other"
+        );
+        assert_eq!(
+            error(&[("A", "\"B\""), ("B", ""), ("C", "\"A\"")]),
+            "neither B nor C beats the other"
+        );
+        assert_eq!(
+            error(&[("A", "\"B\""), ("B", ""), ("C", "\"B\", \"A\"")]),
+            "B doesn't beat anything"
+        );
+        assert_eq!(
+            error(&[("A", "\"B\", \"C\""), ("B", "\"C\""), ("C", "")]),
+            "nothing beats A"
+        );
+
+        let none = format!("weapons = []\n{}", rules(&[]));
+        assert_eq!(
+            none.parse::<Rules>().unwrap_err().to_string(),
+            "expected at least two weapons, found 0"
+        );
+
+        assert_eq!(
+            error(&[("A", "")]),
+            "expected at least two weapons, found 1"
+        );
+        assert_eq!(
+            error(&[("A", "\"A\", \"B\""), ("B", "\"A\"")]),
+            "A beats itself"
+        );
+
+        assert_eq!(error(&[("A", ""), ("A", "")]), "A is listed more than once");
+        assert!(rules(&[("A", "\"B\""), ("B", "\"C\""), ("C", "\"A\"")])
+            .parse::<Rules>()
+            .is_ok());
+    }
+
+    #[test]
+    fn loading() -> Result<()> {
+        assert_eq!(Rules::load(None)?, Rules::default());
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("rules/rock-paper-scissors-lizard-spock.toml");
+        assert_eq!(Rules::load(Some(&path))?.len(), 5);
+        let missing = Path::new("no-such-rules.toml");
+        assert_eq!(
+            Rules::load(Some(missing)).unwrap_err().to_string(),
+            "Reading no-such-rules.toml"
+        );
+        Ok(())
+    }
+}
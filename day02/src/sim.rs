@@ -0,0 +1,245 @@
+use std::fmt::{Display, Formatter};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use thiserror::Error;
+
+use crate::{Guide, Hint, Outcome, Rules, Throw};
+
+#[derive(Debug, Error, PartialEq)]
+pub enum SimError {
+    #[error("expected a weight for each of the {expected} weapons, found {found}")]
+    WeightCount { expected: usize, found: usize },
+    #[error("expected finite weights that are at least 0 and not all 0")]
+    BadWeights,
+}
+
+/// How the guide tells us what to throw.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Follow {
+    /// Throw what each [`Hint`] says, whatever the opponent does.
+    Hints,
+    /// Throw what would get each [`Goal`](crate::Goal)'s outcome, if the opponent threw what the
+    /// guide expects.
+    Goals,
+}
+
+/// What we throw in each round when following `guide`.
+pub fn our_throws(guide: &Guide, follow: Follow) -> Vec<Throw> {
+    match follow {
+        Follow::Hints => guide.hints.iter().map(|h| h.we).collect(),
+        Follow::Goals => guide.goals.iter().map(|g| g.plan(&guide.rules)).collect(),
+    }
+}
+
+/// How an opponent picks its throws.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Opponent {
+    /// Every weapon is as likely as any other.
+    Uniform,
+    /// Each weapon is thrown in proportion to its weight. Made by [`Opponent::biased`].
+    Biased(Weights),
+    /// Guesses our next throw from how often we've thrown each weapon so far this match, and
+    /// throws what beats it.
+    Adaptive,
+}
+
+impl Opponent {
+    /// An opponent weighting the weapons of `rules`, in order, by `weights`.
+    pub fn biased(rules: &Rules, weights: Vec<f64>) -> Result<Opponent, SimError> {
+        if weights.len() != rules.len() {
+            return Err(SimError::WeightCount {
+                expected: rules.len(),
+                found: weights.len(),
+            });
+        }
+        let bad = |w: &f64| !w.is_finite() || *w < 0.0;
+        if weights.iter().any(bad) || weights.iter().sum::<f64>() <= 0.0 {
+            return Err(SimError::BadWeights);
+        }
+        Ok(Opponent::Biased(Weights(weights)))
+    }
+}
+
+/// A weight for each weapon, checked to suit the rules they were made for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Weights(Vec<f64>);
+
+/// Picks an index in proportion to `weights`.
+fn weighted(rng: &mut StdRng, weights: impl Iterator<Item = f64> + Clone) -> usize {
+    let mut r = rng.random::<f64>() * weights.clone().sum::<f64>();
+    let mut last = 0;
+    for (i, w) in weights.enumerate() {
+        if w > 0.0 {
+            if r < w {
+                return i;
+            }
+            r -= w;
+            last = i;
+        }
+    }
+    // Only reached through rounding.
+    last
+}
+
+/// Plays `ours` against `opponent` for one match, and returns our score.
+pub fn play(rules: &Rules, ours: &[Throw], opponent: &Opponent, rng: &mut StdRng) -> i32 {
+    let mut seen = vec![1.0; rules.len()];
+    let mut score = 0;
+    for &we in ours {
+        let they = match opponent {
+            Opponent::Uniform => Throw(rng.random_range(0..rules.len())),
+            Opponent::Biased(Weights(weights)) => Throw(weighted(rng, weights.iter().copied())),
+            Opponent::Adaptive => {
+                let guess = Throw(weighted(rng, seen.iter().copied()));
+                rules.plan(guess, Outcome::Win)
+            }
+        };
+        seen[we.0] += 1.0;
+        score += Hint { they, we }.score(rules);
+    }
+    score
+}
+
+/// Plays `matches` matches of `ours` against `opponent`, seeding the opponent's choices with
+/// `seed` so that the same seed gives the same scores.
+pub fn simulate(
+    rules: &Rules,
+    ours: &[Throw],
+    opponent: &Opponent,
+    matches: usize,
+    seed: u64,
+) -> Distribution {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let scores = (0..matches)
+        .map(|_| play(rules, ours, opponent, &mut rng))
+        .collect();
+    Distribution::new(scores)
+}
+
+/// The spread of scores over many matches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    /// Every match's score, lowest first.
+    pub scores: Vec<i32>,
+}
+
+impl Distribution {
+    pub fn new(mut scores: Vec<i32>) -> Distribution {
+        scores.sort_unstable();
+        Distribution { scores }
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.scores.iter().map(|&s| f64::from(s)).sum::<f64>() / self.scores.len() as f64
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        let mean = self.mean();
+        let variance = self
+            .scores
+            .iter()
+            .map(|&s| (f64::from(s) - mean).powi(2))
+            .sum::<f64>()
+            / self.scores.len() as f64;
+        variance.sqrt()
+    }
+
+    /// The nearest-rank `p`th percentile, or `None` if no matches were played.
+    pub fn percentile(&self, p: u32) -> Option<i32> {
+        let rank = (p as usize * self.scores.len()).div_ceil(100).max(1);
+        self.scores.get(rank - 1).copied()
+    }
+}
+
+impl Display for Distribution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (Some(min), Some(max)) = (self.scores.first(), self.scores.last()) else {
+            return write!(f, "no matches");
+        };
+        write!(
+            f,
+            "mean {:.1}, std dev {:.1}, min {}, p5 {}, median {}, p95 {}, max {}",
+            self.mean(),
+            self.std_dev(),
+            min,
+            self.percentile(5).unwrap(),
+            self.percentile(50).unwrap(),
+            self.percentile(95).unwrap(),
+            max
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, TEST};
+    use anyhow::Result;
+
+    #[test]
+    fn following_the_guide() -> Result<()> {
+        let guide = parse(TEST)?;
+        assert_eq!(our_throws(&guide, Follow::Hints), [1, 0, 2].map(Throw));
+        assert_eq!(our_throws(&guide, Follow::Goals), [0, 0, 0].map(Throw));
+        Ok(())
+    }
+
+    #[test]
+    fn same_seed_same_scores() -> Result<()> {
+        let guide = parse(TEST)?;
+        let ours = our_throws(&guide, Follow::Hints);
+        let a = simulate(&guide.rules, &ours, &Opponent::Uniform, 50, 7);
+        let b = simulate(&guide.rules, &ours, &Opponent::Uniform, 50, 7);
+        assert_eq!(a, b);
+        assert_eq!(a.scores.len(), 50);
+        Ok(())
+    }
+
+    #[test]
+    fn biased_opponents() -> Result<()> {
+        let guide = parse(TEST)?;
+        let rules = &guide.rules;
+        // Always throwing rock, we always draw against rock.
+        let rock = Opponent::biased(rules, vec![1.0, 0.0, 0.0])?;
+        let ours = our_throws(&guide, Follow::Goals);
+        let scores = simulate(rules, &ours, &rock, 20, 1);
+        assert_eq!(scores.percentile(0), Some(12));
+        assert_eq!(scores.percentile(100), Some(12));
+
+        assert_eq!(
+            Opponent::biased(rules, vec![1.0]),
+            Err(SimError::WeightCount {
+                expected: 3,
+                found: 1
+            })
+        );
+        assert_eq!(
+            Opponent::biased(rules, vec![0.0; 3]),
+            Err(SimError::BadWeights)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn adaptive_opponents_punish_habits() -> Result<()> {
+        let guide = parse(&"A X\n".repeat(100))?;
+        let rules = &guide.rules;
+        // Rock every time is soon beaten by paper.
+        let ours = our_throws(&guide, Follow::Hints);
+        let adaptive = simulate(rules, &ours, &Opponent::Adaptive, 20, 3);
+        let uniform = simulate(rules, &ours, &Opponent::Uniform, 20, 3);
+        assert!(adaptive.mean() < uniform.mean());
+        Ok(())
+    }
+
+    #[test]
+    fn distribution() {
+        let d = Distribution::new(vec![3, 1, 2, 4]);
+        assert_eq!(d.mean(), 2.5);
+        assert_eq!(d.percentile(50), Some(2));
+        assert_eq!(d.percentile(100), Some(4));
+        assert!((d.std_dev() - 1.25f64.sqrt()).abs() < 1e-9);
+        assert_eq!(Distribution::new(vec![]).percentile(50), None);
+    }
+}
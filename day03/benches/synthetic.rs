@@ -0,0 +1,114 @@
+//! Bitset rucksacks against the `Vec` and `HashSet` ones they replaced, on synthetic inputs with
+//! many more items per rucksack than the puzzle's.
+
+use std::collections::HashSet;
+use std::hint::black_box;
+
+use aoc_common::bench::criterion::{
+    criterion_group, criterion_main, BenchmarkId, Criterion, Throughput,
+};
+use day03::{badges, priority, Rucksack};
+
+const RUCKSACKS: usize = 3000;
+
+/// `count` rucksacks of `len` items each, from a fixed pseudo-random sequence.
+fn synthetic(count: usize, len: usize) -> String {
+    let letters: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut state: u64 = 0x2022_1203;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        letters[(state >> 33) as usize % letters.len()]
+    };
+    (0..count)
+        .map(|_| (0..len).map(|_| next()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The old representation: each compartment's priorities, in order.
+struct VecRucksack {
+    top: Vec<u32>,
+    bottom: Vec<u32>,
+}
+
+impl VecRucksack {
+    fn new(line: &str) -> VecRucksack {
+        let contents: Vec<u32> = line.chars().filter_map(priority).collect();
+        let (top, bottom) = contents.split_at(contents.len() / 2);
+        VecRucksack {
+            top: top.to_vec(),
+            bottom: bottom.to_vec(),
+        }
+    }
+
+    fn in_both(&self) -> Option<u32> {
+        self.top
+            .iter()
+            .find(|&item| self.bottom.contains(item))
+            .copied()
+    }
+
+    fn all_items(&self) -> HashSet<u32> {
+        HashSet::from_iter(self.top.iter().copied().chain(self.bottom.iter().copied()))
+    }
+}
+
+fn vec_intersect(rs: &[VecRucksack]) -> Option<u32> {
+    rs.iter()
+        .map(|r| r.all_items())
+        .reduce(|a, b| a.intersection(&b).copied().collect())?
+        .into_iter()
+        .next()
+}
+
+fn bench(c: &mut Criterion) {
+    for len in [48, 480, 4800] {
+        let text = synthetic(RUCKSACKS, len);
+        let bits: Vec<Rucksack> = day03::parse(&text)
+            .expect("parsing synthetic input")
+            .rucksacks;
+        let vecs: Vec<VecRucksack> = text.lines().map(VecRucksack::new).collect();
+
+        let mut group = c.benchmark_group("day03 synthetic");
+        group.throughput(Throughput::Elements(RUCKSACKS as u64));
+        group.bench_with_input(BenchmarkId::new("misplaced/vec", len), &vecs, |b, rs| {
+            b.iter(|| {
+                rs.iter()
+                    .map(|r| black_box(r).in_both())
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("misplaced/bitset", len), &bits, |b, rs| {
+            b.iter(|| {
+                rs.iter()
+                    .map(|r| black_box(r).misplaced().first())
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("badges/vec", len), &vecs, |b, rs| {
+            b.iter(|| {
+                black_box(rs)
+                    .chunks(3)
+                    .map(vec_intersect)
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("badges/bitset", len), &bits, |b, rs| {
+            b.iter(|| {
+                black_box(rs)
+                    .chunks(3)
+                    .map(|g| badges(g).first())
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("parse", len), &text, |b, text| {
+            b.iter(|| day03::parse(black_box(text)))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
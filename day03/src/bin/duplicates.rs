@@ -0,0 +1,29 @@
+use std::process::ExitCode;
+
+use anyhow::Result;
+use day03::Layout;
+
+fn main() -> ExitCode {
+    aoc_common::report(run())
+}
+
+/// Takes the input as usual, then optionally the number of compartments and the group size.
+fn run() -> Result<()> {
+    let text = aoc_common::input::load(3, Some(day03::INPUT))?;
+    let layout = Layout::from_args(
+        std::env::args().nth(2).as_deref(),
+        std::env::args().nth(3).as_deref(),
+    )?;
+    let input = day03::parse_with(&text, layout)?;
+
+    for (i, rucksack) in input.rucksacks.iter().enumerate() {
+        for duplicate in rucksack.duplicates() {
+            println!("Rucksack {}: {}", i + 1, duplicate);
+        }
+    }
+    for (i, group) in input.groups().enumerate() {
+        println!("Group {}: badges {}", i + 1, day03::badges(group));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,818 @@
+use anyhow::{Context, Error, Result};
+
+use aoc_common::parse::{self, Located};
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+use std::ops::{BitAnd, BitOr};
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("line {line}, column {column}: expected an item a-z or A-Z, found {text:?}")]
+    BadItem {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    #[error(
+        "line {line}, column {column}: can't split {items} items evenly into {compartments} \
+         compartments: {text:?}"
+    )]
+    UnevenCompartments {
+        line: usize,
+        column: usize,
+        text: String,
+        items: usize,
+        compartments: usize,
+    },
+}
+
+impl Located for ParseError {
+    fn below(mut self, lines: usize) -> Self {
+        match &mut self {
+            ParseError::BadItem { line, .. } | ParseError::UnevenCompartments { line, .. } => {
+                *line += lines
+            }
+        }
+        self
+    }
+}
+
+/// A set of items, as a bit for each priority from 1 to 52.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Items(pub u64);
+
+impl Items {
+    pub fn insert(&mut self, priority: u32) {
+        debug_assert!(
+            (1..=52).contains(&priority),
+            "no item has priority {}",
+            priority
+        );
+        self.0 |= 1 << priority;
+    }
+
+    pub fn contains(&self, priority: u32) -> bool {
+        priority < 64 && self.0 & (1 << priority) != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// The lowest priority in the set, if any.
+    pub fn first(&self) -> Option<u32> {
+        (!self.is_empty()).then(|| self.0.trailing_zeros())
+    }
+
+    /// Every priority in the set, lowest first.
+    pub fn priorities(&self) -> impl Iterator<Item = u32> {
+        let bits = self.0;
+        (1..=52).filter(move |p| bits & (1 << p) != 0)
+    }
+}
+
+impl Display for Items {
+    /// Lists the items, like "p, L".
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.priorities().map(item).join(", "))
+    }
+}
+
+impl BitAnd for Items {
+    type Output = Items;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Items(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for Items {
+    type Output = Items;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Items(self.0 | rhs.0)
+    }
+}
+
+impl FromIterator<u32> for Items {
+    fn from_iter<T: IntoIterator<Item = u32>>(iter: T) -> Self {
+        let mut items = Items::default();
+        for priority in iter {
+            items.insert(priority);
+        }
+        items
+    }
+}
+
+/// The item with priority `priority`, which must be from 1 to 52.
+pub fn item(priority: u32) -> char {
+    match priority {
+        1..=26 => char::from(b'a' + priority as u8 - 1),
+        27..=52 => char::from(b'A' + priority as u8 - 27),
+        _ => panic!("no item has priority {}", priority),
+    }
+}
+
+/// The priority of the item `ch`, if it is one.
+pub fn priority(ch: char) -> Option<u32> {
+    match ch {
+        'a'..='z' => Some(u32::from(ch) - u32::from('a') + 1),
+        'A'..='Z' => Some(u32::from(ch) - u32::from('A') + 27),
+        _ => None,
+    }
+}
+
+/// How the elves pack: how many compartments each rucksack has, and how many elves are in a
+/// group.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Layout {
+    compartments: usize,
+    group_size: usize,
+}
+
+impl Layout {
+    pub fn new(compartments: usize, group_size: usize) -> Result<Layout> {
+        if compartments < 2 {
+            return Err(Error::msg(format!(
+                "Expected at least 2 compartments, found {}",
+                compartments
+            )));
+        }
+        if group_size == 0 {
+            return Err(Error::msg("Expected groups of at least 1 elf"));
+        }
+        Ok(Layout {
+            compartments,
+            group_size,
+        })
+    }
+
+    /// The layout given by a program's optional arguments: the number of compartments, then the
+    /// group size. Either left out keeps the puzzle's.
+    pub fn from_args(compartments: Option<&str>, group_size: Option<&str>) -> Result<Layout> {
+        let default = Layout::default();
+        let arg = |arg: Option<&str>, default: usize| match arg {
+            Some(arg) => arg
+                .parse()
+                .with_context(|| format!("Expected a number, found {:?}", arg)),
+            None => Ok(default),
+        };
+        Layout::new(
+            arg(compartments, default.compartments)?,
+            arg(group_size, default.group_size)?,
+        )
+    }
+
+    pub fn compartments(&self) -> usize {
+        self.compartments
+    }
+
+    pub fn group_size(&self) -> usize {
+        self.group_size
+    }
+}
+
+impl Default for Layout {
+    /// Two compartments, and groups of three, as in the puzzle.
+    fn default() -> Self {
+        Layout {
+            compartments: 2,
+            group_size: 3,
+        }
+    }
+}
+
+/// Where an item was packed: its compartment and its column in the line, both counting from 1.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Position {
+    pub compartment: usize,
+    pub column: usize,
+}
+
+/// An item packed in more than one compartment, and everywhere it was packed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Duplicate {
+    pub priority: u32,
+    pub positions: Vec<Position>,
+}
+
+impl Display for Duplicate {
+    /// Like "p in compartment 1 at column 15, compartment 2 at column 21".
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} in {}",
+            item(self.priority),
+            self.positions
+                .iter()
+                .map(|p| format!("compartment {} at column {}", p.compartment, p.column))
+                .join(", ")
+        )
+    }
+}
+
+/// The items in each compartment of a rucksack.
+#[derive(Debug)]
+pub struct Rucksack {
+    pub compartments: Vec<Items>,
+    contents: String,
+}
+
+impl Rucksack {
+    /// Reads a rucksack whose items are split evenly between the compartments of `layout`.
+    pub fn parse_with(s: &str, layout: Layout) -> Result<Rucksack, ParseError> {
+        let compartments = layout.compartments;
+        // Items are all ASCII, so any other character is an error before the sizes matter.
+        let size = s.len() / compartments;
+        let mut rucksack = Rucksack {
+            compartments: vec![Items::default(); compartments],
+            contents: s.to_string(),
+        };
+        for (i, ch) in s.char_indices() {
+            let item = priority(ch).ok_or_else(|| ParseError::BadItem {
+                line: 1,
+                column: i + 1,
+                text: ch.to_string(),
+            })?;
+            if let Some(compartment) = rucksack.compartments.get_mut(i / size.max(1)) {
+                compartment.insert(item);
+            }
+        }
+
+        if !s.len().is_multiple_of(compartments) {
+            return Err(ParseError::UnevenCompartments {
+                line: 1,
+                column: 1,
+                text: s.to_string(),
+                items: s.len(),
+                compartments,
+            });
+        }
+
+        Ok(rucksack)
+    }
+
+    /// The items packed in more than one compartment.
+    pub fn misplaced(&self) -> Items {
+        let mut seen = Items::default();
+        let mut twice = Items::default();
+        for &compartment in &self.compartments {
+            twice = twice | (seen & compartment);
+            seen = seen | compartment;
+        }
+        twice
+    }
+
+    /// Every item packed in more than one compartment, with everywhere it was packed.
+    pub fn duplicates(&self) -> Vec<Duplicate> {
+        let size = self.contents.len() / self.compartments.len();
+        self.misplaced()
+            .priorities()
+            .map(|priority| Duplicate {
+                priority,
+                positions: self
+                    .contents
+                    .chars()
+                    .enumerate()
+                    .filter(|&(_, ch)| ch == item(priority))
+                    .map(|(i, _)| Position {
+                        compartment: i / size + 1,
+                        column: i + 1,
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    pub fn all_items(&self) -> Items {
+        self.compartments
+            .iter()
+            .fold(Items::default(), |all, &compartment| all | compartment)
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = ParseError;
+
+    /// Reads a rucksack with two compartments.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rucksack::parse_with(s, Layout::default())
+    }
+}
+
+/// The items carried by every one of `rs`: a group's badge, if the group is packed properly.
+pub fn badges(rs: &[Rucksack]) -> Items {
+    rs.iter()
+        .map(|r| r.all_items())
+        .reduce(|a, b| a & b)
+        .unwrap_or_default()
+}
+
+/// Every elf's rucksack, and how they were packed.
+#[derive(Debug)]
+pub struct Inventory {
+    pub layout: Layout,
+    pub rucksacks: Vec<Rucksack>,
+}
+
+impl Inventory {
+    /// The rucksacks of each group of elves. The last group may be short.
+    pub fn groups(&self) -> std::slice::Chunks<'_, Rucksack> {
+        self.rucksacks.chunks(self.layout.group_size)
+    }
+}
+
+/// Reads rucksacks packed as in the puzzle.
+pub fn parse(input: &str) -> Result<Inventory, ParseError> {
+    parse_with(input, Layout::default())
+}
+
+pub fn parse_with(input: &str, layout: Layout) -> Result<Inventory, ParseError> {
+    let rucksacks = parse::lines_with(input, |l| Rucksack::parse_with(l, layout))?;
+    Ok(Inventory { layout, rucksacks })
+}
+
+/// The one item in `items`, or an error saying what `what` held instead.
+fn only(items: Items, what: impl FnOnce() -> String, holding: &str) -> Result<u32> {
+    match items.len() {
+        1 => Ok(items.first().unwrap()),
+        0 => Err(Error::msg(format!("{} has no {}", what(), holding))),
+        _ => Err(Error::msg(format!(
+            "{} has more than one {}: {}",
+            what(),
+            holding,
+            items
+        ))),
+    }
+}
+
+pub fn part_1(input: &Inventory) -> Result<u32> {
+    input
+        .rucksacks
+        .iter()
+        .enumerate()
+        .map(|(i, r)| {
+            only(
+                r.misplaced(),
+                || format!("Rucksack {}", i + 1),
+                "item in more than one compartment",
+            )
+        })
+        .sum()
+}
+
+pub fn part_2(input: &Inventory) -> Result<u32> {
+    let size = input.layout.group_size;
+    if !input.rucksacks.len().is_multiple_of(size) {
+        return Err(Error::msg(format!(
+            "Can't split {} rucksacks into groups of {}",
+            input.rucksacks.len(),
+            size
+        )));
+    }
+
+    input
+        .groups()
+        .enumerate()
+        .map(|(i, group)| only(badges(group), || format!("Group {}", i + 1), "badge"))
+        .sum()
+}
+
+pub const INPUT: &str = r#"jLnFTjhwFTLFDGDDvLgvDssBJBbVRNZJPPJBGzBNRVJNRB
+QWmffSmMZCfWrmHlCflQWfSNBpVBNbPSbbJNppcVVzzpcp
+lflrqrWMmfdMlrtWWmZgDjsqwFwhFDsngnvhqs
+CNBGwsWVsdWDNCCVvfPLfQfgZHPBvpgf
+qhJqJlmcFnhJclllPgZjvpHpvfZpPbLn
+lpMJJhhcSMDMMwws
+jphtLMfVpjJRjJscMJptssnrwdNwQrNbwNzQTbcNrwQT
+gSPPSqGGGrdLrNQGrr
+PvWPDvWWCSSBBZPjLVDfhjsLLsDhtR
+hlLwDGLNnTrbNdtbdb
+ZfsvJbsJTrsttHTR
+vjPQJPFcmZjVFZFvbbVcJZJPBBhLMnDBznnGMDLMwBhlmBBl
+JdTJFtwHjJTzPMqPccqzjB
+rggffSCvNBCbWBWt
+ZsSpnnRpLStrZpTDwTVwGTJHDLdH
+sZqswsrcRpCrsCsrrbtpbpDlttlFbbpGtj
+LhNgNvNNhTQMhQMWhvvhfWhllbGGPbPtlPmFlDbjHHdlmg
+ffNBNMhDvQDhvzhBhNTBnSzzrZCZnrsVCSwcnrCC
+hHGhjLjwtFGwGhhhhtwjtlhrDBQrRBHczRcMvsBcRrDDMH
+TTfbZdZbPbVCmCdmnZmCVdfrJBczMrrznvBvQBzzzcBscn
+fdbPZTVVfSdmbfdvjjSGljhhtNGGhL
+fMrGQbPrbnfljjzVLQzNCzVv
+WcDqlSpctSJNzSFJLCzN
+hBBWcHtWgWDmZlwwrsPrRTRTMrmn
+ZJTVdgpzPpLVVwZgPzTPLwgWdhcWhSQCbcSbBtSWdSWchb
+qnDGnNjqDqNlqsCSWhMtlMltWSQB
+NnNrFQGjDRgLrgrpPr
+nCrhsmVrlrzsTvsnVvmTTlpvPJPWWMPJWWPCJMPNPgPJMjJj
+ctDqBLHLRLBSBqFRDBFqFFNGgjjrGGMrWftWJPGgwfGN
+HFcqZqqdcFQRQBdrlnplhspQlTmmvlph
+mllQMvMTMMdNNdTzTGmLgWjBgGtBqWHhGWjHtB
+PPsbbZPCVfJFJRJZsBHjjHhHcjqSSWBt
+wCVJFVRJDDpCPqZlTlwQnrvzmMdmml
+JfCJtCwfsffBJmFmgFmFhhdwgV
+lzHzsSHvSRlDRjlHTgqVVmmVPZqqmggmqS
+GzNNRTWvbWGvGQtfLnbfMbJfsC
+FnPrwqrFqsPrrsrwqwnbZFPwBQMhhdBHQZhTVBMdDHVhQQVh
+zlLWlLvLgtgbMdhfVDdzfd
+NWRNjLmWblGFsssNnSwPsw
+gnmCjzwnmCPTPhBwPjzBgqPjllJJSWlhfhQDSrpJRhDSlfJl
+rLHNHrLHVNbVHMMctZFHsbcsDSDWpSDSGfSRsRWSRllfGSSG
+NNtdMVrLNdZNvLvLZrzCndqBgwwPmwgjggBn
+tGSBRwwStBftfBHRScHwfcrCgQgDnjQhQDqgBqgCCDnnqg
+LNlbZmlPVNzWlLZVFPQqgDTQTmjqCQDQqmsQ
+bpZNZLNNZlLNLZNNZbLlbLZbwrwSHGJSwrGwRtRpcjjtMttw
+JvGNPWhLPLBffwhGCrbgnFDbgcZwbVnb
+TqzSTlQzdHQQpTTjSddHTSnbqnZDcrrFnFVFVbVVcbhc
+mlpdjhhTHWBmWWBJvG
+ScrppccsbMRRvltvrvrj
+HRTVTnDwDLJJJZwwVQTmNlvqBNtvWBBBQvhNfqlB
+TLJdLLCJZHJCVHHTwRdcsGpSbgsczcdcgS
+tffHzJctHsSHHHGTtzvttZFlZZhVjglVmgDhDdjgjS
+qCBMCqbpqfQPMBPpPljQVDDZdddZdgFlDV
+fWwLfbbWNzGGrzvWHJ
+VnNFqrrJdfQhCDFPhttD
+pGpTTzHmRTNTTLRRtbBBbzhPtbtbPPBt
+HLpHpmSRHTpllcLTSgVvNrJVfJvqMnZN
+tHzgwJCgzZlLFLnWJTnc
+NnNnmsMnBDFSFWdqssFd
+mDMBNmRRjjMNnvrPnjjRQQvfRgwpgHHpppHhZbzfCpbHtH
+TTwBnnZwBnNQZZWLWTTFNFbsCGrGFCNvDDDGFj
+lzPRfVVRMclPRMRcbFDjfCCGvvsdGCDd
+hSmmlMlVztlhRlVbqQpLnWqSqLZZwZQT
+zzVTcqmVqzdmhZJbFpQZQQtLLWLLbp
+TGwvGgTnGTRHLNtMftWHNbWf
+vljTGwvvlCljsgSnCBslDPPJrPdVzrSmhdqhVDPP
+LzCGCGLBGNlLmFFmmSSBBqSc
+ZJfbdfbWtbgVlblJtjcFcqTmFqqjtFHtjj
+QgZhZgQVdlfddlWPhfvMCGRGPDMMRvDNGvND
+nLdpLJSSnLMwjCDwnQhw
+NZGNgmsgPtPTgWqqCGwRbrDCQbCwhMjr
+gNqPTqTPttPZqcWfHLlBvccFvhpvJc
+GNdGhMMvRBjmMVjM
+trcZJJFmCgfZtcrQCfCZSpqSRjBQpQRQBbBqsSqR
+JcTmwtTtcrmThLGDhNTThvNW
+ZqhmtzzfZRsSfRmSTThMpLNgpgJQThMG
+jdnvdHWFvvjFdCWFrvVVnvFDQrJJMpgMJGNLJNJTPPgQGJGR
+vnHjDVHBWjdncDRbRzzzmmBsSSzt
+VZNZSCPTJPpvNTNsCrbnVrLccbLnnRVfWb
+MlqQBlBztFlhQJdFhccbhnRWWfnggfGc
+lzJqllwQtmzdHZpvwjPPNspj
+wTnCMnwQCQvTTJdfqhdtrrSmhttmmm
+LZlZglZBvjgbHHSrHpbr
+GWvPjPNGljBzMzJVMwNJMw
+CSlChlvPPGvdddqPqSJhPdbNbcMTMTbDbcRNDFcFLMLJ
+HWjgngmBZGQgRRNbFRcHbbzT
+BmnpnQBBssBsQBnPptSStllGhdCGrS
+nhQCnCvjchBBjMNrwbRwZvvSvSWW
+qgHlfqblVbPfVqlbGgPGfdfLSdNssWWLZsNsLZsdNdLSZZ
+gbGHzzbzHtPfcJhczmMnQBJn
+TTGJJPPZJGRzDwtQjTjzDC
+VbnrmNVWVWbffbVMsrbrnvFRjzCzQCjvQDDStDFS
+MhrRfMmfMmsHgsqPLLGdBqJJGHqd
+FMCMbmSQTHdCBmpFHprdHcDNqqfqJdJsDcclDDZlNf
+wzwRjwwtRhnzjRBWRnvtnWtnNsfsVDlDlsqVNNNqcqVlDvqf
+PtnGWjtwLBwtjCFPFpMpMbSrQm
+PWzrtrtzBBfjjWGbfNWvLJLgVSjRLVlSlllLpS
+HhZmHGGhGZRpQlpLpL
+dTdnHCnhmhcHcntrPPGfzfrcNtPz
+PfmWWWPwWdPdWPTVCdpjRnzhjDbrFnGbnQGQrrDR
+LSBnZLnJBQDLRGcQGc
+NHNqqBqvMMBsJgNvgsstBHNsdwwfTtwdpfTTmVmnWmtmWVlp
+zjzwTHCzDzbDzNTDjzDCCHRZmmSGmfgPmZGtHtgLmf
+hsqJMcQltLtPhLgL
+QWWWcMsWFJssrnsWrdlcPBwNTdzwTvvBvjwpTbBzpT
+LRvWZdLzzZWSqdpBzLdzLgRmbHPVHtFHMFFMCtHCfHcbVcqb
+sGGjNDnGTnlSSwsrTwcFVMHwCPPPcVfVHwPM
+GDQsDlJlQhSDTrpvhhWBLWvdRvWh
+sWhhdhdhbHSVBbFLZLnnCmCmtTTCCV
+RcQvjNrcrwGNcGNRJJmTpppgCCgppNgTtFmp
+GRRRGRGclfjwRsbfHFhWBDhdFs
+jfRTfgHqgDZHClcPcrGhnbcqrr
+FztdMLGBzpFJpcnzzclblcnbbl
+pdNBtdNLdvsNtdLpdGLTZDTgDjmvCZjCRCDDHg
+bdrbdZbJtZhrMrWRHHvRHRvHllqZ
+GGjCNgDVDFsCCwFNHjlrPrlSRWPcSlvl
+CNVDgDwVrQggDDQVsDwNwgTQdJtbfbLhtTdtLmnfbJMntnft
+mjznjLchnPPcLLnwCTFFvPgJJWdqJqJJdJJr
+bMlQRzbSBbRBMlMfMBBZFrqggvVvfqdDVDVDWWFd
+ZSSsRGQtSpBBpZMSbSpZsbtsjhCHTHcLCcjzjCwTTHhmNHtT
+ThCHLCfVfDTtDnDppTqclFfSclNrwNPwNfFr
+JmsQjJPJMWRRMddZGZWsdsMZrmcqvNSFSwrScrvwSwcccFFw
+ZWdJQGGzPJsMRZBGtHLgCHTTVTLBDtgn
+JVGJfttHgTLVTsnHgVHfwVBDjQDNQSjQwjbNbQjBbddS
+rPvZcZpcvtzcZddmNQjWQSjDpp
+rrRZPzRMztFFFqMZFtRVgnsLnVHfVHqJnghHJL
+grrZZbJSggQDLgrvSSDBVsGdjHjnGnBGVsLGHj
+pCtWtPtffPRcWdBsGsGRBRvhBj
+zmvNNclfZJJFZQbN
+DDqCgPPDgtDSPPDbgCqcmBMBTQLQZBWwQZbsMWZZMw
+RNlNvpvNvndNjMZtBpwMTWLBZt
+VlNJvRzdlGFRFGRRRjFVJRRggtPPHgHzgStqCcHHmHqcrc
+RfmdFgbtFLHlsjRPsL
+LqrhvCTvNNhWBvBTrThrDWZrPjMQPQMcjzsPcQHTlVHQjcMM
+BhhrJWJLvCbfJbwgtgJn
+FWpRfFRjtjFpVvbTTZFPhJhJLdCLdWBhPmHmCW
+McMzNlGrgGGsHcqmdLqbhJmd
+lNMwsnMzzsSSGtVVSfvZTtSppb
+JrJQDJDVvGwGVwvcGCRRcCBBqsWBRZqWZZ
+bNdmhvMpjmHbNbpNvmbBqlRWlCtCqlRZqRtW
+HmTjNdmMfTTVvLSzvz
+WMQWmfGfQfVnMGWGmGtGWHzZtdLzpZhwrtZcwZzdps
+jBgljNPbNjPFRbggNlNBCcHsrLLLHlrLslhlzdhdHppp
+BBPBJbFCTGJcVQMGMv
+fdmfmmjbPmjlmfhfGglTNLCNvPNSpPvpvSLCPCpS
+MtJwMrwrZJVHMgqJHtwVJzVVLCvpNLvDrLvQSQSNcCNpFNLS
+wWMVWZzqqMRHztwJZzRqVtjGlBdhbGGbThhTlnTBTWgb
+ShhrjPrvhlljnHrggPvvSqRMBZZqBTZmMmTmTMdD
+ctLzbcpWCJbNWRQqBBdZFMBdfZdJBm
+GQWpzLcVpVNpRggHrhhgvnGPwj
+PdzSrmmqSgWWddNQds
+TZbwCTLZbHLWptQHhpgNsc
+MZjBLjMLDTDqrrGflsjjrf
+DBHTnlGGBPjPmwRWhn
+hgLgZccSrcMgLMSpCLJjNWmPWjmVWdwJjd
+MfQMScQrMSprrTGHBhqTvqlGQT
+CDvLRCCzQDWzcGQGvRcpVFVsgTsFrgllrTpF
+mjbqtHqHbspspnMv
+ZvvqdqqHvLhDhJWzJd
+ThHnbRTVbQHSWvBVVBCPBW
+FFJgfqfqNwNrvDCBNppP
+jzsFfqwqssLzJjffJcssHQHQdnMdhTGHLbddbCnn
+gwMHHhbSwnqHrgMqMbnBcldhzBLQBQhBLtLttQ
+dCRmpfvpCzcQllflQz
+TjPsCJmvZPNPsvZpPTTRCpprjdbrHngqDwqMrDnMbMbHgM
+CgNQpgTCgNltHTNQlHpRnRnzBfJnPnmpcpfq
+LGrrvGSMGvrsWPBmnJPmPfmrqr
+VGsSvdJvbSHVtlttNVHT
+LnZDtzpzNpPsCbfBbDCDBC
+gSGRSSghqhhSvSRvvhvVqFJdsfBsCHJbVfddCJ
+vglRRjQSmnQQCNrL
+HwhVddVVwRcGHGjlfS
+MbBvDBGvWqpNWvMbzDMNZZlLcjlSclflcjqgfCRg
+bvDWDWQbspPPWWQMnGdnFnJtJFGJhhPJ
+nDJDdQdPDlDJnnbRQDlTqVfFwfVtjJjjFfqqFqgN
+hScrSmSCTZvZCSmZLFwfffqgjfhNGwjhfw
+zCzsCCTcMBmSLmsMWHdPWRQPQPddDQ
+lzGflPZLSqSlccpqjpdNCwjdsjCnCsJp
+VghPQDVMFQNdJjRNBw
+tVrhDVmHTlPlGrzL
+gfSJJlzvGgRPpggR
+VLDDHhHtlZnnZqbGmtqPmmmmWN
+VVDBnddDBFFzlscC
+cmmzRzcRjFrGncTHqc
+ghJfwJJvqhbrdndWgddWBH
+fQbSpJwSthbhtvpCwqQbsDPMNLlpRmplzPlRMPzM
+bBVQDgBpTzbhpgQVhTmBVBzgrvcnrncsnZrwzHJZfnHcZfrP
+tdWlFLMHGFGRGMRLNFHlfwJcrPwcsnfwcvZPfr
+RLWGjHLjSjtjmBQVgpQhhg
+ttRRJBrCfpprmFqSJVTSwNJNTWlVTn
+nLsMghDbQdnhwWNLjcwNSlVH
+ZZDgnDQvDdndPCmfrRqzvFqpmp
+PPzCrcGGlnhPcsdfNZZdpLLNZsfd
+RjjWHjVWDvjWqVMHjHJjVlHdwwJbLfNZdfdgfpppgpfpZw
+lBVvqjQqBDBlVmCFGChhnSmTTrmr
+BZnGZFvGvBGGrwLBZlzNgmzMhMdfmwgmdmmt
+cpDHtVjDWjCtDTCcDNhhzNJszgzMTJhmgg
+VWWcRWDpPDPPtHtScqFrLlBFFFSFBqZLSF
+qBFcCGFcHHGBldzBLtdzSrdR
+fpmfpswfQNQmfPHfPPgJNwRtngTtSTRzdTrLRnSnldLd
+fsHNpNPwQZwhVHMFGqvvMcqWFGVF
+FwFJlqDDwwgFHQdmFmSQ
+pPtzcrLHZrQfdsBsdLdf
+bHrrHjbrjbrJvDvbqMVNqJ
+WVVpPHPfWnHmpccpnpvQcrgNNMSCTMjMgjjMMN
+RsZbhRFTDDJtZFJhTLRRLMrMrCdSNCdSMjrNhjNGSQ
+LLqtqqzwqmmflPTq
+mmLhdZSdqhqnqMZShLQrMWcrcWQwTgrWVVWc
+CCvjjsfJRCCJbDbFsbGJvzgWWGlWrWlwTQrwQGGcHlBg
+NRDbRCNsNjQPSSLZNdLn
+cmVLcQZPSHBGlSddBf
+DFNzDNszlbBlgHNN
+tWRzFjsWWtrtlDztzwjDjThLPhRMpppLMhmMhnmncRcn
+LFFcgdNLpLQggZQMFMcNJggRHCHsTtRtHTzCTZHRsfSRfs
+qnhlhmGrrhhnqdmGhdhWbnSRzSfsBRSTSRtBflRffzfz
+PnbmrmGjWMVNvdPFLp
+swcWsMMmVwWwFtWlbnnZnFBhbNfrhN
+dQLZHjpDQCfBBBBTGTTC
+DJvDpDSzSDQZMWsWwPqVtwcS
+NVTMjjGGNBqhNttdTTjtcqjCfDlhZhSWCFFJmSmZmWJhmZ
+rznsHPnRLzwspZDWfGZJSLZZDC
+QzPRRvRQszQsGQcVNqTBBdVj
+rHGBglBNLpbqCgFC
+PbmbRnmMSbRPDWRnDVSMPMWmvvcvqqppTLZZFpvCpczLcCVv
+dsdMSPtMdPnSffQwGGsBBbQw
+nqjTlGPGnqWsMPPwGdGjQvQvJCCQBvmvCvQJtmCw
+DDhVbDrcVVFZLzHrZBRNCWStmQWztJvtCm
+VHDLhFFDpcHZcHGWfsGlspTTMqps
+FTPZwBzHHFLTTSwHLJTwVQqnDrzgRRVgQpDVRprn
+jJMjsWlvQqVrqgWW
+CjNsjjvlmslbcJmmbtCtCThdwhHLhhhLPdBCSPFh
+jfgllWfLrfmlTjsCjcjDNNDnNcHJnd
+MMBRSRZGppGFpBbbBGvVnFHhcCJqNcNcHCFNgnnn
+pMZZgBRVZVGRtRSMbMbvRQmTswWfzLzLzWLrPQmfwLlW
+tzpdHLNpfpPnlNFHnpssbgShtqcgTTggrsqg
+JZwWGCWMCcbsSHgWjq
+QCmDZCJwmvZvCVCVRQGCDMFBNlfHmfLLzPPfdpfBLlPB
+PCvRTdPPBVBTGgLGrGqMrMnv
+twQwStrsQwstjSHmmpqZpwqNGnGnNnZgLN
+mJScfjbttQjQtjcmfbbQJHFTBfdfRhhrPhhTfPVVhPPB
+DMPJmLLjhJwNBCNBww
+TpccnsZsbTHpsTHVszbpbGHSNwBtWlfrtCtCfrtlBlFZlf
+HsHvccvzbqsGzqnnHvLQPghQNNDDvLLjdjLR
+zmgVMrrjjMGbbzzdqqrgVbRTHqTTThpNHphNTvvvTNnv
+QLSmZmSJTvTnZTvn
+SfDtDDmQCFDwWmSrjPPGfjMjjGfMgV
+hdNHNHjnGHMTcpWlhBchRBJR
+fPztCTPtttLwLzVbwqSVtCBBDpWvlcRRlBpbDBQWcDWJ
+ftrCVzfTCFFzTFPrPjNZFHnNsMdZgjHdss
+TVcTbdqLqzHHRNHM
+PppQTWffPfCPQSsCprzRwRJDzMDzNwRBRPHN
+pjCTZjTjmTtsZhGcGdLvdlgv
+VJRhVfJRBgbdrrrvJpJJ
+cCSSHSHnZPwDFNHCDCQdbQQdvqnMsrpqQnvq
+NFFCwSrDzCNrwhtgzhWtBhgzWB
+RRBRczzcvmfzVRzVPvPTlCWnnlTGqGMgTTnG
+tNLdSdNSMFhsFttddtjlwGqllqQglqTWqwnThT
+rLSsLrNdZbbHjLdSFFMjLLtSfVRJzVJJVZcRfvZVRfDpVvRz
+DBsdssbdbDfPnnqzzJbqStqzjjJS
+RcGMGwwmrJjFFHPG
+mCgwMLPgCPmmwWDBDZvWvBCWfZ
+cJCGJJMZcMGWMhWCJTfFSfDmmgFmtshggffr
+vBVQBDbNstQmFsfm
+RVwHNblzwHvNdvBwNjVHGZJZLJTMLMJDZLGRMDGG
+LNCssCldDbrnNtVWNQ
+mmcpRBMBRBTZzgMMhtFFvrWQrQmWFtvn
+MZTgzMZSzTfSMDHwCfPlLLDnwL
+HlPcjMSHsMSPbgLhFPFNzP
+ZfRhtVdGBNLFzLqf
+DRtGGtRGJJTJDZDGdCJrVJmlWvHWcvjMsMWmhQsmCMjW
+swpQcsBvvHFpBHHcCHJvJddvbbWPdzhzzqqWdhPnhP
+gDDGTgjNWZTMTNjjggRLggldSPqSqGznnhnbdfnbqfhh
+WDMDtDNDctVFJstJ
+rFDbGjrGbpBBbrCbrwpvvZQZZhgTLVVVVLgThCTggg
+NlfHpttqzltMzRSMSdWRNSfSTTmqgcLgQVcQTcmgQgmZVPVZ
+HWSWpMHNpNWlpNMsMzWGGwwBvvwBnwBvsnnvnB
+mZVVHmmRZlvdjvNpHh
+DFBnFDtnFbBMrQbBMvFLvgtjjNpcLpJdNzJJdhWNpJNWcN
+QTsbrQggBsvsgVTwRVGZCfGZfR
+RGNGlGTWJnJlMcRMGqvSmnDtvDDvtLSLvr
+HfsFhPPdVshfbggDVmzcvSwvStVzDq
+BPPfCgCFfcBbPbHbBHZPFsbBpMGlClRNJRlQTjQWjWpTTlGT
+HQNqQbbRQHbZPZQPqbZTvWFWmCjvFRvRTSrRrr
+lBpBpzLLdBwsLwLLWNggzCFTCmFgWmrF
+VwsswGVBfJfccLBcsJGNdpJHZZqnhtqqnhPHDZHVtPQMZn
+LMHnHnPMnFdJFNFJjZvrJF
+wlTlslptlwtzcvTqvTsVSWWrbJfmJZNZJjcNWfNJmf
+qDlqVthTztSSpSlqtpTTzLvRRnnCvRCPLBQGDBdDGD
+HlmlJgRBBpJLpPBHnlLJsrgJDMttvSdddcMSStWjjWtcSzjs
+qfTbNPTVGhGFCVGTwZfNZNNVWvjSczcjStMMcdSczzczvbcW
+GZVqffQTwNQTmPRlggHLprHQ
+BjsWschcTjjWclQTSnZFrjtFtbzZfqntzF
+qggNGMHgvHMRZMtznbrftMCn
+qvHdqpwgJTsVccwscT
+FJwNPHJmPHTJHglTFwgmwqtShBqDmDBzzBzWhCDWqQ
+fpsjNsMVLVjDCCSzBtjztt
+sLrdfbsMcbMRZZZbbsfrsrNgGNglRwllRnJnwFvnFHnG
+lWWVTJlJCTTzDdWzqdtFFt
+PvPvrhbjhgSjvJQSnQRbjZDZFFHMtGGqFtRMGZFtGH
+rhPrSgfvJPfmwsTpLcsV"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = r#"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw"#;
+
+    #[test]
+    fn part_1_sample() -> Result<()> {
+        assert_eq!(part_1(&parse(TEST)?)?, 157);
+        Ok(())
+    }
+
+    #[test]
+    fn items() {
+        let items: Items = "vJrwpWtwJgWr".chars().filter_map(priority).collect();
+        assert_eq!(items.len(), 8);
+        assert!(items.contains(priority('W').unwrap()));
+        assert!(!items.contains(priority('w').unwrap() + 1));
+        assert_eq!(items.first(), priority('g'));
+        let both = items & "hcsFMMfFFhFp".chars().filter_map(priority).collect();
+        assert_eq!(
+            both.priorities().collect::<Vec<_>>(),
+            priority('p').into_iter().collect::<Vec<_>>()
+        );
+
+        assert_eq!(Items::default().first(), None);
+    }
+
+    #[test]
+    fn part_2_sample() -> Result<()> {
+        assert_eq!(part_2(&parse(TEST)?)?, 70);
+        Ok(())
+    }
+
+    #[test]
+    fn three_compartments() -> Result<()> {
+        let input = parse_with("abcAbC\naaxyza", Layout::new(3, 2)?)?;
+        assert_eq!(input.rucksacks[0].compartments.len(), 3);
+        // b is in the first and last compartments, and a in the first twice and the last.
+        assert_eq!(
+            part_1(&input)?,
+            priority('b').unwrap() + priority('a').unwrap()
+        );
+
+        let input = parse_with("abcAbC\nabyzaz", Layout::new(3, 2)?)?;
+
+        assert_eq!(
+            input.rucksacks[1].duplicates(),
+            vec![
+                Duplicate {
+                    priority: priority('a').unwrap(),
+                    positions: vec![
+                        Position {
+                            compartment: 1,
+                            column: 1
+                        },
+                        Position {
+                            compartment: 3,
+                            column: 5
+                        }
+                    ]
+                },
+                Duplicate {
+                    priority: priority('z').unwrap(),
+                    positions: vec![
+                        Position {
+                            compartment: 2,
+                            column: 4
+                        },
+                        Position {
+                            compartment: 3,
+                            column: 6
+                        }
+                    ]
+                }
+            ]
+        );
+        assert_eq!(
+            input.rucksacks[1].duplicates()[0].to_string(),
+            "a in compartment 1 at column 1, compartment 3 at column 5"
+        );
+        assert_eq!(
+            part_1(&input).unwrap_err().to_string(),
+            "Rucksack 2 has more than one item in more than one compartment: a, z"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn explicit_errors() -> Result<()> {
+        assert_eq!(
+            parse_with("abcd\nabcde", Layout::new(2, 1)?)
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: can't split 5 items evenly into 2 compartments: \"abcde\""
+        );
+        assert_eq!(
+            part_1(&parse("abcd")?).unwrap_err().to_string(),
+            "Rucksack 1 has no item in more than one compartment"
+        );
+        assert_eq!(
+            part_2(&parse_with("aa\nbb", Layout::new(2, 2)?)?)
+                .unwrap_err()
+                .to_string(),
+            "Group 1 has no badge"
+        );
+        assert_eq!(
+            part_2(&parse_with("abab\nabab", Layout::new(2, 2)?)?)
+                .unwrap_err()
+                .to_string(),
+            "Group 1 has more than one badge: a, b"
+        );
+        assert_eq!(
+            part_2(&parse("aa\nbb")?).unwrap_err().to_string(),
+            "Can't split 2 rucksacks into groups of 3"
+        );
+        assert!(Layout::new(1, 3).is_err());
+        assert!(Layout::new(2, 0).is_err());
+        assert_eq!(Layout::from_args(None, Some("2"))?, Layout::new(2, 2)?);
+        assert_eq!(
+            Layout::from_args(Some("x"), None).unwrap_err().to_string(),
+            "Expected a number, found \"x\""
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use std::process::ExitCode;
+
+use anyhow::Result;
+use day04::{Coverage, ElfId};
+
+fn main() -> ExitCode {
+    aoc_common::report(run())
+}
+
+fn run() -> Result<()> {
+    let text = aoc_common::input::load(4, Some(day04::INPUT))?;
+    let input = day04::parse(&text)?;
+    let coverage = Coverage::new(&input);
+
+    println!("Covered: {}", coverage.covered());
+    let uncovered = coverage.uncovered();
+    if uncovered.is_empty() {
+        println!("Uncovered: none");
+    } else {
+        println!("Uncovered: {}", uncovered);
+    }
+    if let Some((most, sections)) = coverage.most_covered() {
+        println!("Most covered: {} by {} elves", sections, most);
+    }
+
+    let mergeable = day04::mergeable(&input);
+    println!("{} pairs could be merged:", mergeable.len());
+    for (pair, merged) in mergeable {
+        println!("  line {}: {}", pair + 1, merged);
+    }
+
+    let cover = coverage.minimal_cover();
+    println!("{} elves cover every section:", cover.len());
+    for ElfId { pair, elf } in cover {
+        let which = if elf == 0 { "first" } else { "second" };
+        let sections = if elf == 0 {
+            input[pair].0
+        } else {
+            input[pair].1
+        };
+        println!("  line {}, {} elf: {}", pair + 1, which, sections);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,221 @@
+use aoc_common::{Interval, IntervalSet};
+
+use crate::Assignment;
+
+/// One elf of a pair: `pair` is its assignment's index in the input, from 0, and `elf` is 0 or 1
+/// for the first or second range on that line.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct ElfId {
+    pub pair: usize,
+    pub elf: usize,
+}
+
+/// Every elf's sections, indexed to answer questions about the whole input.
+#[derive(Debug, Clone)]
+pub struct Coverage {
+    /// Every elf's sections, by where they start.
+    elves: Vec<(Interval, ElfId)>,
+    /// How many elves cover each stretch of sections, from a sweep over where ranges start and
+    /// end. Stretches nobody covers are left out.
+    depths: Vec<(Interval, usize)>,
+    covered: IntervalSet,
+}
+
+impl Coverage {
+    pub fn new(assignments: &[Assignment]) -> Coverage {
+        let mut elves: Vec<(Interval, ElfId)> = assignments
+            .iter()
+            .enumerate()
+            .flat_map(|(pair, a)| [(a.0, ElfId { pair, elf: 0 }), (a.1, ElfId { pair, elf: 1 })])
+            .collect();
+        elves.sort_unstable_by_key(|&(interval, id)| (interval.start, interval.end, id));
+
+        // A range adds an elf from its first section, and takes it away after its last, unless
+        // nothing comes after it.
+        let mut events: Vec<(i64, isize)> = elves
+            .iter()
+            .flat_map(|(iv, _)| {
+                let after = iv.end.checked_add(1).map(|after| (after, -1));
+                [(iv.start, 1)].into_iter().chain(after)
+            })
+            .collect();
+        events.sort_unstable();
+
+        let mut depths = Vec::new();
+        let mut depth = 0;
+        for (i, &(at, change)) in events.iter().enumerate() {
+            depth += change;
+            match events.get(i + 1) {
+                Some(&(next, _)) if next > at && depth > 0 => {
+                    depths.push((Interval::new(at, next - 1), depth as usize))
+                }
+                None if depth > 0 => depths.push((Interval::new(at, i64::MAX), depth as usize)),
+                _ => {}
+            }
+        }
+
+        let covered = elves.iter().map(|&(interval, _)| interval).collect();
+        Coverage {
+            elves,
+            depths,
+            covered,
+        }
+    }
+
+    /// Every section at least one elf covers.
+    pub fn covered(&self) -> &IntervalSet {
+        &self.covered
+    }
+
+    /// The sections from 1 up to the last one anybody covers that nobody does.
+    pub fn uncovered(&self) -> IntervalSet {
+        match self.covered.hull() {
+            Some(hull) => match Interval::try_new(1.min(hull.start), hull.end) {
+                Some(bounds) => self.covered.gaps(bounds),
+                None => IntervalSet::new(),
+            },
+            None => IntervalSet::new(),
+        }
+    }
+
+    /// How many elves cover `section`.
+    pub fn depth(&self, section: i64) -> usize {
+        let i = self.depths.partition_point(|(iv, _)| iv.end < section);
+        match self.depths.get(i) {
+            Some(&(iv, depth)) if iv.contains(section) => depth,
+            _ => 0,
+        }
+    }
+
+    /// The most elves covering any one section, and every section that many cover. `None` if
+    /// there are no elves.
+    pub fn most_covered(&self) -> Option<(usize, IntervalSet)> {
+        let most = self.depths.iter().map(|&(_, depth)| depth).max()?;
+        let sections = self
+            .depths
+            .iter()
+            .filter(|&&(_, depth)| depth == most)
+            .map(|&(iv, _)| iv)
+            .collect();
+        Some((most, sections))
+    }
+
+    /// The fewest elves that between them cover every section anybody does. Of elves that would
+    /// do as well as each other, the first in the input is picked.
+    pub fn minimal_cover(&self) -> Vec<ElfId> {
+        let mut chosen = Vec::new();
+        let mut next = 0;
+        for run in self.covered.iter() {
+            // Greedily take whichever elf starting by the first uncovered section reaches
+            // furthest, until the whole run is covered.
+            let mut from = run.start;
+            while from <= run.end {
+                let mut best: Option<(Interval, ElfId)> = None;
+                while let Some(&(iv, id)) = self.elves.get(next) {
+                    if iv.start > from {
+                        break;
+                    }
+                    if best.is_none_or(|(b, b_id)| (iv.end, b_id) > (b.end, id)) {
+                        best = Some((iv, id));
+                    }
+                    next += 1;
+                }
+                let (iv, id) = best.expect("every section of a run is covered");
+                chosen.push(id);
+                match iv.end.checked_add(1) {
+                    Some(after) => from = after,
+                    None => break,
+                }
+            }
+        }
+        chosen
+    }
+}
+
+/// Each pair whose two ranges could be merged into one, by index, and what they'd merge into.
+pub fn mergeable(assignments: &[Assignment]) -> Vec<(usize, Interval)> {
+    assignments
+        .iter()
+        .enumerate()
+        .filter_map(|(i, a)| Some((i, a.merged()?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, TEST};
+    use anyhow::Result;
+
+    #[test]
+    fn sample_coverage() -> Result<()> {
+        let coverage = Coverage::new(&parse(TEST)?);
+        assert_eq!(coverage.covered().intervals(), &[Interval::new(2, 9)]);
+        assert_eq!(coverage.uncovered().intervals(), &[Interval::new(1, 1)]);
+        let depths: Vec<usize> = (1..=10).map(|s| coverage.depth(s)).collect();
+        assert_eq!(depths, vec![0, 4, 5, 7, 7, 8, 6, 4, 1, 0]);
+        assert_eq!(
+            coverage.most_covered(),
+            Some((8, IntervalSet::from(Interval::new(6, 6))))
+        );
+        assert_eq!(
+            coverage.minimal_cover(),
+            vec![ElfId { pair: 3, elf: 0 }, ElfId { pair: 2, elf: 1 }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn gaps_and_runs() -> Result<()> {
+        let coverage = Coverage::new(&parse("3-4,8-9\n4-5,9-12\n8-10,4-4")?);
+        assert_eq!(
+            coverage.uncovered(),
+            [Interval::new(1, 2), Interval::new(6, 7)]
+                .into_iter()
+                .collect()
+        );
+        assert_eq!(
+            coverage.most_covered(),
+            Some((
+                3,
+                [Interval::new(4, 4), Interval::new(9, 9)]
+                    .into_iter()
+                    .collect()
+            ))
+        );
+        assert_eq!(coverage.minimal_cover().len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn sample_mergeable() -> Result<()> {
+        let pairs: Vec<usize> = mergeable(&parse(TEST)?).iter().map(|&(i, _)| i).collect();
+        assert_eq!(pairs, vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            mergeable(&parse("2-3,4-5")?),
+            vec![(0, Interval::new(2, 5))]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn nobody() {
+        let coverage = Coverage::new(&[]);
+        assert!(coverage.uncovered().is_empty());
+        assert_eq!(coverage.most_covered(), None);
+        assert!(coverage.minimal_cover().is_empty());
+    }
+
+    #[test]
+    fn last_section() -> Result<()> {
+        let max = i64::MAX;
+        let coverage = Coverage::new(&parse(&format!("5-{max},{}-{max}", max - 1))?);
+        assert_eq!(coverage.depth(max), 2);
+        assert_eq!(
+            coverage.most_covered(),
+            Some((2, IntervalSet::from(Interval::new(max - 1, max))))
+        );
+        assert_eq!(coverage.minimal_cover(), vec![ElfId { pair: 0, elf: 0 }]);
+        Ok(())
+    }
+}
@@ -0,0 +1 @@
+aoc_common::bench_day!(5, day05);
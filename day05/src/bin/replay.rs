@@ -0,0 +1,107 @@
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use day05::{Alternating, CrateMover9000, CrateMover9001, Limited, Replay};
+
+fn main() -> ExitCode {
+    aoc_common::report(run())
+}
+
+/// Takes the input as usual, then optionally the crane (`9000`, `9001`, `limited:N` for one
+/// lifting at most N crates at once, or `alternating`) and how to replay: `step` through the
+/// moves from stdin, `trace` every state, or `animate` them. Stepping reads its commands from
+/// stdin, so its input must come from a file or the embedded puzzle input rather than stdin.
+fn run() -> Result<()> {
+    let mode = std::env::args().nth(3);
+    let stepping = matches!(mode.as_deref(), None | Some("step"));
+    let text = match std::env::args().nth(1).as_deref() {
+        Some("-") if stepping => {
+            bail!("Stepping reads its commands from stdin, so the input can't come from there too")
+        }
+        None if stepping => aoc_common::input::load_default(5, Some(day05::INPUT))?,
+        _ => aoc_common::input::load(5, Some(day05::INPUT))?,
+    };
+    let (stacks, moves) = day05::parse(&text)?;
+    let mut replay = match std::env::args().nth(2).as_deref() {
+        None | Some("9000") => Replay::record(&mut CrateMover9000, &stacks, &moves)?,
+        Some("9001") => Replay::record(&mut CrateMover9001, &stacks, &moves)?,
+        Some("alternating") => Replay::record(&mut Alternating::default(), &stacks, &moves)?,
+        Some(other) => match other.strip_prefix("limited:").map(str::parse) {
+            Some(Ok(capacity)) if capacity > 0 => {
+                Replay::record(&mut Limited::new(capacity), &stacks, &moves)?
+            }
+            _ => bail!(
+                "Expected crane 9000, 9001, limited:N with N at least 1, or alternating, found {:?}",
+                other
+            ),
+        },
+    };
+
+    match mode.as_deref() {
+        None | Some("step") => step(&mut replay),
+        Some("trace") => {
+            for n in 0..=replay.len() {
+                replay.jump(n);
+                show(&replay);
+                println!();
+            }
+            Ok(())
+        }
+        Some("animate") => animate(&replay),
+        Some(other) => bail!("Expected step, trace or animate, found {:?}", other),
+    }
+}
+
+/// Prints where the replay is and the stacks there.
+fn show(replay: &Replay) {
+    match replay.last_move() {
+        Some(m) => println!(
+            "After move {} of {}: {}",
+            replay.position(),
+            replay.len(),
+            m
+        ),
+        None => println!("Before any of the {} moves", replay.len()),
+    }
+    println!("{}", day05::draw(replay.stacks()));
+}
+
+fn step(replay: &mut Replay) -> Result<()> {
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        show(replay);
+        print!("[n]ext, [b]ack, a move number, or [q]uit: ");
+        io::stdout().flush()?;
+        let Some(line) = lines.next().transpose()? else {
+            println!();
+            return Ok(());
+        };
+        let moved = match line.trim() {
+            "" | "n" => replay.forward(),
+            "b" => replay.back(),
+            "q" => return Ok(()),
+            other => other.parse().is_ok_and(|n| replay.jump(n)),
+        };
+        if !moved {
+            println!("Can't go there");
+        }
+        println!();
+    }
+}
+
+fn animate(replay: &Replay) -> Result<()> {
+    for n in 1..=replay.len() {
+        for frame in replay.frames(n).unwrap_or_default() {
+            // Clear the screen and start again from the top.
+            print!("\x1b[2J\x1b[H");
+            println!("Move {} of {}", n, replay.len());
+            println!("{}", frame);
+            io::stdout().flush()?;
+            thread::sleep(Duration::from_millis(120));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use crate::{Invalid, Move, MoveError};
+
+/// A model of crane, which decides how the crates of a [`Move`] travel from one stack to
+/// another.
+pub trait Crane {
+    /// Carries out `m` on `stacks`, which it may assume is [valid](Move::validate).
+    ///
+    /// May panic if it isn't: see [`Crane::try_exec`].
+    fn exec(&mut self, m: &Move, stacks: &mut [Vec<char>]);
+
+    /// Carries out `m` on `stacks` if it's valid, and otherwise leaves them alone.
+    fn try_exec(&mut self, m: &Move, stacks: &mut [Vec<char>]) -> Result<(), Invalid> {
+        m.validate(stacks)?;
+        self.exec(m, stacks);
+        Ok(())
+    }
+
+    /// Carries out every move of `procedure`, in order, on a copy of `stacks`, stopping at the
+    /// first that isn't valid.
+    fn rearrange(
+        &mut self,
+        stacks: &[Vec<char>],
+        procedure: &[Move],
+    ) -> Result<Vec<Vec<char>>, MoveError> {
+        let mut stacks = stacks.to_vec();
+        for (i, m) in procedure.iter().enumerate() {
+            self.try_exec(m, &mut stacks).map_err(|reason| MoveError {
+                number: i + 1,
+                reason,
+            })?;
+        }
+        Ok(stacks)
+    }
+}
+
+/// Lifts the top `n` crates off `src` and sets them down on `dest`, either as they were or
+/// turned upside down.
+fn lift(stacks: &mut [Vec<char>], src: usize, dest: usize, n: usize, flip: bool) {
+    let pos = stacks[src].len() - n;
+    let mut load = stacks[src].split_off(pos);
+    if flip {
+        load.reverse();
+    }
+    stacks[dest].append(&mut load);
+}
+
+/// Moves crates one at a time, so they land in the opposite order.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn exec(&mut self, m: &Move, stacks: &mut [Vec<char>]) {
+        lift(stacks, m.src, m.dest, m.quantity, true);
+    }
+}
+
+/// Moves all the crates at once, so they keep their order.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn exec(&mut self, m: &Move, stacks: &mut [Vec<char>]) {
+        lift(stacks, m.src, m.dest, m.quantity, false);
+    }
+}
+
+/// Moves at most `capacity` crates at once, splitting bigger moves into as many full lifts as
+/// it takes. Each lift keeps its crates' order, so a capacity of 1 works like the
+/// [`CrateMover9000`], and one at least as big as any move like the [`CrateMover9001`].
+#[derive(Debug, Copy, Clone)]
+pub struct Limited {
+    capacity: usize,
+}
+
+impl Limited {
+    /// Panics if `capacity` is 0, as such a crane couldn't move anything.
+    pub fn new(capacity: usize) -> Limited {
+        assert!(capacity > 0, "a crane must lift at least one crate");
+        Limited { capacity }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl Crane for Limited {
+    fn exec(&mut self, m: &Move, stacks: &mut [Vec<char>]) {
+        let mut left = m.quantity;
+        while left > 0 {
+            let n = left.min(self.capacity);
+            lift(stacks, m.src, m.dest, n, false);
+            left -= n;
+        }
+    }
+}
+
+/// Moves all the crates at once, but turns every other load upside down: the first move lands
+/// like the [`CrateMover9000`]'s, the second like the [`CrateMover9001`]'s, and so on.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Alternating {
+    /// Whether the next load keeps its order.
+    keep: bool,
+}
+
+impl Crane for Alternating {
+    fn exec(&mut self, m: &Move, stacks: &mut [Vec<char>]) {
+        lift(stacks, m.src, m.dest, m.quantity, !self.keep);
+        self.keep = !self.keep;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, tops, TEST};
+    use anyhow::Result;
+
+    fn tops_after(crane: &mut impl Crane) -> Result<String> {
+        let (stacks, moves) = parse(TEST)?;
+        Ok(tops(&crane.rearrange(&stacks, &moves)?))
+    }
+
+    #[test]
+    fn limited_cranes() -> Result<()> {
+        assert_eq!(tops_after(&mut Limited::new(1))?, "CMZ");
+        assert_eq!(tops_after(&mut Limited::new(2))?, "MCZ");
+        assert_eq!(tops_after(&mut Limited::new(3))?, "MCD");
+        Ok(())
+    }
+
+    #[test]
+    fn alternating_crane() -> Result<()> {
+        assert_eq!(tops_after(&mut Alternating::default())?, "CMD");
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_moves_change_nothing() {
+        let mut stacks = vec![vec!['A', 'B', 'C'], vec![]];
+        let too_many = Move {
+            quantity: 4,
+            src: 0,
+            dest: 1,
+        };
+        let mut crane = Alternating::default();
+        assert!(crane.try_exec(&too_many, &mut stacks).is_err());
+        assert_eq!(stacks, [vec!['A', 'B', 'C'], vec![]]);
+
+        // The crane still flips its first load.
+        let two = Move {
+            quantity: 2,
+            src: 0,
+            dest: 1,
+        };
+        crane.try_exec(&two, &mut stacks).unwrap();
+        assert_eq!(stacks, [vec!['A'], vec!['C', 'B']]);
+
+        let err = crane.rearrange(&stacks, &[two]).unwrap_err();
+        assert_eq!(err.to_string(), "move 1: stack 1 has 1 crate(s), needs 2");
+    }
+}
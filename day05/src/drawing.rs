@@ -0,0 +1,60 @@
+use itertools::Itertools;
+
+use crate::Move;
+
+/// Draws `stacks` the way the puzzle does: a row for each level of crates, from the top down,
+/// then a row numbering the stacks from 1. Every row is as wide as the stacks take, padded with
+/// spaces, and there's no newline after the last.
+pub fn draw(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let rows = (0..height).rev().map(|level| {
+        stacks
+            .iter()
+            .map(|s| match s.get(level) {
+                Some(ch) => format!("[{}]", ch),
+                None => "   ".to_string(),
+            })
+            .join(" ")
+    });
+    let footer = (1..=stacks.len()).map(|n| format!("{:^3}", n)).join(" ");
+    rows.chain([footer]).join("\n")
+}
+
+/// Writes out a puzzle input: the drawing of `stacks`, a blank line, then `procedure`.
+pub fn write_input(stacks: &[Vec<char>], procedure: &[Move]) -> String {
+    format!("{}\n\n{}", draw(stacks), procedure.iter().join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, INPUT, TEST};
+    use anyhow::Result;
+
+    #[test]
+    fn sample_round_trip() -> Result<()> {
+        let (stacks, moves) = parse(TEST)?;
+        assert_eq!(write_input(&stacks, &moves), TEST);
+        Ok(())
+    }
+
+    #[test]
+    fn input_round_trip() -> Result<()> {
+        let (stacks, moves) = parse(INPUT)?;
+        let written = write_input(&stacks, &moves);
+        // The saved input's rows have lost their trailing spaces.
+        assert!(written.lines().map(str::trim_end).eq(INPUT.lines()));
+
+        let (again, _) = parse(&written)?;
+        assert_eq!(again, stacks);
+        Ok(())
+    }
+
+    #[test]
+    fn empty_stacks() -> Result<()> {
+        let stacks = vec![vec![], vec!['A'], vec![]];
+        assert_eq!(draw(&stacks), "    [A]    \n 1   2   3 ");
+        assert_eq!(parse(&write_input(&stacks, &[]))?.0, stacks);
+        Ok(())
+    }
+}
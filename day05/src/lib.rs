@@ -0,0 +1,758 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::Result;
+use aoc_common::parse::{self, Located};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+use thiserror::Error;
+
+mod crane;
+mod drawing;
+mod replay;
+mod validate;
+
+pub use crane::{Alternating, Crane, CrateMover9000, CrateMover9001, Limited};
+pub use drawing::{draw, write_input};
+pub use replay::Replay;
+
+pub use validate::{dry_run, Invalid, MoveError};
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("line {line}: expected a blank line between the drawing and the moves")]
+    MissingMoves { line: usize },
+    #[error(
+        "line {line}, column {column}: expected a move like \"move 1 from 2 to 3\", found {text:?}"
+    )]
+    BadMove {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    #[error("line {line}, column {column}: bad number {text:?}")]
+    BadNumber {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    #[error("line {line}, column {column}: there are only {stacks} stacks: {text:?}")]
+    NoSuchStack {
+        line: usize,
+        column: usize,
+        text: String,
+        stacks: usize,
+    },
+}
+
+impl Located for ParseError {
+    fn below(mut self, lines: usize) -> Self {
+        match &mut self {
+            ParseError::MissingMoves { line }
+            | ParseError::BadMove { line, .. }
+            | ParseError::BadNumber { line, .. }
+            | ParseError::NoSuchStack { line, .. } => *line += lines,
+        }
+        self
+    }
+}
+
+/// A step of the rearrangement procedure, with stacks numbered from 0.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Move {
+    pub quantity: usize,
+    pub src: usize,
+    pub dest: usize,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+        }
+
+        let caps = RE.captures(s).ok_or_else(|| ParseError::BadMove {
+            line: 1,
+            column: 1,
+            text: s.to_string(),
+        })?;
+        let number = |i: usize| {
+            let m = caps.get(i).unwrap();
+            match m.as_str().parse::<usize>() {
+                Ok(n) if i == 1 || n > 0 => Ok(n),
+                _ => Err(ParseError::BadNumber {
+                    line: 1,
+                    column: m.start() + 1,
+                    text: m.as_str().to_string(),
+                }),
+            }
+        };
+
+        Ok(Move {
+            quantity: number(1)?,
+            src: number(2)? - 1,
+            dest: number(3)? - 1,
+        })
+    }
+}
+
+impl Display for Move {
+    /// As the procedure writes it, with stacks numbered from 1.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.quantity,
+            self.src + 1,
+            self.dest + 1
+        )
+    }
+}
+
+pub fn parse(input: &str) -> Result<(Vec<Vec<char>>, Vec<Move>), ParseError> {
+    let (drawing, procedure) =
+        input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::MissingMoves {
+                line: input.lines().count() + 1,
+            })?;
+    let stacks = drawing
+        .lines()
+        .map(|l| {
+            l.chars()
+                .chunks(4)
+                .into_iter()
+                .map(|mut chs| {
+                    chs.nth(1).and_then(|ch| {
+                        if ch == ' ' || ch.is_ascii_digit() {
+                            None
+                        } else {
+                            Some(ch)
+                        }
+                    })
+                })
+                .collect::<Vec<Option<char>>>()
+        })
+        .fold(Vec::new(), |mut acc, row| {
+            // The row numbering the stacks reaches the last one, so empty stacks still count.
+            if acc.len() < row.len() {
+                acc.resize(row.len(), Vec::new());
+            }
+            row.iter().enumerate().for_each(|(i, ch)| {
+                if let Some(c) = ch {
+                    acc[i].insert(0, *c);
+                }
+            });
+            acc
+        });
+
+    // The moves start after the drawing and the blank line.
+    let offset = drawing.lines().count() + 1;
+    let moves: Vec<Move> = parse::lines(procedure).map_err(|e: ParseError| e.below(offset))?;
+
+    for (i, m) in moves.iter().enumerate() {
+        // The source and destination are the fourth and sixth words of "move 1 from 2 to 3".
+        let Some(word) = [(m.src, 3), (m.dest, 5)]
+            .into_iter()
+            .find(|&(stack, _)| stack >= stacks.len())
+            .map(|(_, word)| word)
+        else {
+            continue;
+        };
+        let line = procedure.lines().nth(i).unwrap_or_default();
+        let number = line.split(' ').nth(word).unwrap_or(line);
+        return Err(ParseError::NoSuchStack {
+            line: offset + i + 1,
+            column: parse::column(line, number),
+            text: line.to_string(),
+            stacks: stacks.len(),
+        });
+    }
+
+    Ok((stacks, moves))
+}
+
+/// The crate on top of each stack, with a space for an empty stack.
+fn tops(stacks: &[Vec<char>]) -> String {
+    stacks
+        .iter()
+        .map(|s| s.last().copied().unwrap_or(' '))
+        .collect()
+}
+
+pub fn part_1((diagram, moves): &(Vec<Vec<char>>, Vec<Move>)) -> Result<String> {
+    Ok(tops(&CrateMover9000.rearrange(diagram, moves)?))
+}
+
+pub fn part_2((diagram, moves): &(Vec<Vec<char>>, Vec<Move>)) -> Result<String> {
+    Ok(tops(&CrateMover9001.rearrange(diagram, moves)?))
+}
+
+pub const INPUT: &str = r#"        [H]         [S]         [D]
+    [S] [C]         [C]     [Q] [L]
+    [C] [R] [Z]     [R]     [H] [Z]
+    [G] [N] [H] [S] [B]     [R] [F]
+[D] [T] [Q] [F] [Q] [Z]     [Z] [N]
+[Z] [W] [F] [N] [F] [W] [J] [V] [G]
+[T] [R] [B] [C] [L] [P] [F] [L] [H]
+[H] [Q] [P] [L] [G] [V] [Z] [D] [B]
+ 1   2   3   4   5   6   7   8   9
+
+move 2 from 7 to 2
+move 1 from 4 to 8
+move 2 from 1 to 9
+move 4 from 6 to 5
+move 1 from 7 to 6
+move 2 from 1 to 4
+move 7 from 8 to 9
+move 7 from 4 to 5
+move 4 from 2 to 4
+move 1 from 5 to 9
+move 14 from 5 to 4
+move 1 from 3 to 8
+move 5 from 4 to 8
+move 1 from 2 to 5
+move 2 from 4 to 1
+move 6 from 8 to 1
+move 1 from 8 to 6
+move 1 from 2 to 5
+move 5 from 3 to 7
+move 2 from 6 to 3
+move 2 from 4 to 7
+move 3 from 3 to 9
+move 7 from 4 to 1
+move 1 from 6 to 9
+move 2 from 6 to 1
+move 3 from 5 to 2
+move 1 from 1 to 8
+move 21 from 9 to 1
+move 1 from 4 to 2
+move 7 from 7 to 2
+move 1 from 4 to 2
+move 23 from 1 to 5
+move 5 from 5 to 1
+move 1 from 3 to 6
+move 1 from 6 to 3
+move 12 from 1 to 6
+move 1 from 3 to 6
+move 2 from 1 to 8
+move 1 from 9 to 3
+move 2 from 8 to 1
+move 2 from 1 to 8
+move 1 from 1 to 3
+move 2 from 3 to 1
+move 2 from 8 to 1
+move 3 from 6 to 1
+move 1 from 8 to 7
+move 4 from 6 to 2
+move 3 from 6 to 9
+move 2 from 5 to 7
+move 2 from 7 to 8
+move 1 from 7 to 9
+move 9 from 1 to 5
+move 12 from 5 to 9
+move 1 from 8 to 6
+move 1 from 6 to 9
+move 1 from 6 to 9
+move 7 from 9 to 4
+move 10 from 2 to 1
+move 12 from 5 to 4
+move 7 from 4 to 9
+move 7 from 4 to 7
+move 1 from 5 to 4
+move 7 from 7 to 8
+move 1 from 6 to 3
+move 1 from 3 to 1
+move 3 from 2 to 4
+move 1 from 6 to 8
+move 7 from 1 to 2
+move 1 from 6 to 7
+move 12 from 9 to 4
+move 3 from 8 to 5
+move 1 from 7 to 3
+move 6 from 9 to 1
+move 10 from 1 to 9
+move 7 from 9 to 5
+move 3 from 9 to 5
+move 1 from 3 to 4
+move 2 from 2 to 1
+move 1 from 5 to 1
+move 9 from 4 to 3
+move 1 from 1 to 3
+move 8 from 4 to 7
+move 7 from 5 to 3
+move 2 from 7 to 2
+move 8 from 3 to 9
+move 1 from 1 to 8
+move 10 from 2 to 3
+move 4 from 8 to 7
+move 12 from 3 to 4
+move 9 from 7 to 2
+move 2 from 1 to 3
+move 1 from 9 to 6
+move 2 from 4 to 9
+move 1 from 7 to 6
+move 5 from 5 to 9
+move 8 from 3 to 1
+move 2 from 6 to 3
+move 14 from 4 to 3
+move 15 from 3 to 9
+move 1 from 3 to 1
+move 3 from 9 to 8
+move 1 from 8 to 1
+move 1 from 3 to 2
+move 5 from 2 to 8
+move 1 from 4 to 2
+move 2 from 1 to 3
+move 2 from 3 to 9
+move 3 from 2 to 4
+move 6 from 1 to 8
+move 2 from 2 to 6
+move 1 from 6 to 4
+move 2 from 4 to 7
+move 5 from 8 to 5
+move 1 from 6 to 9
+move 7 from 9 to 6
+move 1 from 5 to 3
+move 2 from 7 to 8
+move 2 from 2 to 4
+move 3 from 5 to 6
+move 1 from 3 to 8
+move 1 from 5 to 6
+move 2 from 4 to 1
+move 3 from 1 to 6
+move 21 from 9 to 5
+move 1 from 4 to 3
+move 1 from 4 to 9
+move 2 from 9 to 2
+move 1 from 3 to 9
+move 4 from 2 to 3
+move 3 from 8 to 1
+move 14 from 5 to 9
+move 7 from 5 to 4
+move 3 from 8 to 4
+move 4 from 3 to 2
+move 3 from 8 to 5
+move 1 from 2 to 3
+move 1 from 5 to 1
+move 2 from 5 to 4
+move 3 from 2 to 9
+move 11 from 4 to 1
+move 17 from 9 to 2
+move 17 from 2 to 9
+move 10 from 9 to 2
+move 2 from 8 to 2
+move 3 from 8 to 3
+move 8 from 9 to 7
+move 4 from 7 to 3
+move 2 from 3 to 2
+move 3 from 2 to 3
+move 9 from 3 to 5
+move 1 from 1 to 9
+move 8 from 5 to 1
+move 2 from 7 to 9
+move 24 from 1 to 3
+move 24 from 3 to 6
+move 1 from 5 to 3
+move 10 from 2 to 1
+move 1 from 4 to 5
+move 3 from 9 to 1
+move 1 from 3 to 5
+move 17 from 6 to 5
+move 1 from 7 to 4
+move 13 from 5 to 4
+move 3 from 5 to 8
+move 1 from 7 to 9
+move 3 from 6 to 9
+move 8 from 6 to 4
+move 1 from 9 to 6
+move 11 from 1 to 8
+move 1 from 5 to 6
+move 12 from 4 to 9
+move 2 from 5 to 1
+move 1 from 1 to 7
+move 5 from 9 to 2
+move 1 from 7 to 9
+move 3 from 1 to 5
+move 3 from 5 to 9
+move 7 from 9 to 3
+move 4 from 9 to 6
+move 3 from 6 to 8
+move 5 from 4 to 3
+move 2 from 2 to 6
+move 3 from 9 to 3
+move 3 from 6 to 4
+move 4 from 2 to 6
+move 11 from 3 to 5
+move 11 from 6 to 9
+move 2 from 3 to 5
+move 1 from 5 to 8
+move 3 from 6 to 2
+move 7 from 9 to 2
+move 8 from 5 to 7
+move 6 from 4 to 5
+move 2 from 4 to 3
+move 1 from 8 to 6
+move 4 from 8 to 3
+move 13 from 8 to 3
+move 1 from 9 to 5
+move 6 from 7 to 2
+move 1 from 7 to 6
+move 1 from 6 to 5
+move 2 from 6 to 7
+move 13 from 3 to 5
+move 6 from 2 to 7
+move 1 from 6 to 1
+move 1 from 2 to 8
+move 2 from 7 to 8
+move 14 from 5 to 8
+move 1 from 1 to 4
+move 9 from 2 to 1
+move 14 from 8 to 7
+move 3 from 3 to 9
+move 11 from 5 to 3
+move 1 from 4 to 5
+move 4 from 9 to 8
+move 4 from 8 to 7
+move 5 from 3 to 9
+move 11 from 7 to 8
+move 9 from 1 to 3
+move 4 from 3 to 2
+move 6 from 8 to 4
+move 2 from 8 to 2
+move 13 from 3 to 6
+move 1 from 4 to 1
+move 5 from 4 to 2
+move 10 from 2 to 6
+move 4 from 9 to 1
+move 8 from 7 to 8
+move 10 from 8 to 5
+move 2 from 3 to 2
+move 2 from 8 to 6
+move 1 from 7 to 1
+move 2 from 7 to 6
+move 2 from 2 to 9
+move 2 from 8 to 6
+move 6 from 1 to 7
+move 5 from 9 to 1
+move 4 from 7 to 8
+move 1 from 7 to 2
+move 2 from 1 to 7
+move 1 from 3 to 8
+move 1 from 1 to 6
+move 2 from 2 to 6
+move 1 from 7 to 8
+move 1 from 1 to 9
+move 8 from 5 to 7
+move 2 from 7 to 9
+move 9 from 6 to 3
+move 13 from 6 to 8
+move 3 from 9 to 1
+move 5 from 6 to 1
+move 3 from 8 to 1
+move 3 from 3 to 4
+move 1 from 4 to 3
+move 1 from 4 to 8
+move 4 from 6 to 3
+move 11 from 8 to 2
+move 1 from 6 to 9
+move 8 from 3 to 9
+move 3 from 5 to 8
+move 4 from 1 to 2
+move 6 from 8 to 5
+move 6 from 5 to 1
+move 5 from 1 to 3
+move 3 from 3 to 4
+move 3 from 8 to 4
+move 2 from 4 to 5
+move 10 from 7 to 8
+move 5 from 9 to 2
+move 1 from 7 to 5
+move 3 from 5 to 2
+move 4 from 9 to 3
+move 4 from 1 to 5
+move 1 from 3 to 2
+move 3 from 5 to 2
+move 6 from 2 to 5
+move 10 from 8 to 3
+move 4 from 4 to 5
+move 4 from 2 to 8
+move 12 from 3 to 8
+move 1 from 1 to 3
+move 9 from 8 to 6
+move 1 from 4 to 1
+move 6 from 8 to 7
+move 3 from 1 to 7
+move 9 from 5 to 7
+move 11 from 7 to 2
+move 2 from 7 to 3
+move 9 from 2 to 7
+move 1 from 8 to 7
+move 1 from 5 to 2
+move 2 from 6 to 2
+move 2 from 1 to 2
+move 6 from 3 to 5
+move 2 from 3 to 6
+move 4 from 7 to 3
+move 3 from 3 to 1
+move 2 from 1 to 5
+move 7 from 7 to 6
+move 1 from 1 to 5
+move 3 from 2 to 4
+move 1 from 3 to 2
+move 18 from 2 to 1
+move 4 from 2 to 7
+move 6 from 5 to 9
+move 1 from 4 to 8
+move 2 from 6 to 1
+move 19 from 1 to 2
+move 4 from 9 to 5
+move 5 from 7 to 2
+move 1 from 8 to 7
+move 1 from 1 to 2
+move 6 from 5 to 7
+move 1 from 3 to 8
+move 6 from 7 to 6
+move 1 from 4 to 1
+move 4 from 7 to 9
+move 1 from 1 to 3
+move 1 from 2 to 5
+move 1 from 4 to 8
+move 1 from 3 to 4
+move 3 from 5 to 4
+move 2 from 8 to 9
+move 9 from 2 to 4
+move 19 from 6 to 4
+move 1 from 4 to 7
+move 5 from 9 to 5
+move 10 from 2 to 9
+move 2 from 5 to 4
+move 14 from 4 to 7
+move 2 from 2 to 1
+move 3 from 9 to 1
+move 1 from 1 to 3
+move 13 from 7 to 6
+move 1 from 5 to 9
+move 1 from 6 to 9
+move 1 from 7 to 2
+move 5 from 9 to 7
+move 1 from 5 to 2
+move 3 from 7 to 3
+move 3 from 4 to 9
+move 1 from 5 to 2
+move 4 from 4 to 2
+move 2 from 7 to 3
+move 4 from 1 to 6
+move 1 from 7 to 9
+move 11 from 9 to 5
+move 8 from 2 to 9
+move 6 from 9 to 6
+move 8 from 4 to 5
+move 14 from 5 to 6
+move 1 from 5 to 4
+move 3 from 5 to 1
+move 1 from 5 to 2
+move 2 from 6 to 4
+move 2 from 4 to 2
+move 1 from 9 to 2
+move 1 from 2 to 3
+move 1 from 9 to 3
+move 3 from 2 to 7
+move 7 from 6 to 7
+move 5 from 4 to 3
+move 23 from 6 to 1
+move 5 from 7 to 2
+move 22 from 1 to 6
+move 6 from 6 to 3
+move 6 from 2 to 4
+move 6 from 4 to 1
+move 3 from 7 to 8
+move 3 from 1 to 8
+move 4 from 3 to 2
+move 1 from 1 to 3
+move 3 from 3 to 1
+move 1 from 7 to 5
+move 1 from 6 to 5
+move 1 from 7 to 4
+move 4 from 6 to 9
+move 5 from 3 to 6
+move 2 from 2 to 1
+move 3 from 9 to 4
+move 11 from 1 to 9
+move 2 from 4 to 7
+move 4 from 6 to 1
+move 1 from 5 to 4
+move 5 from 8 to 9
+move 1 from 7 to 1
+move 3 from 2 to 7
+move 4 from 1 to 2
+move 3 from 4 to 2
+move 1 from 8 to 5
+move 1 from 5 to 4
+move 1 from 5 to 4
+move 5 from 6 to 1
+move 3 from 7 to 6
+move 5 from 2 to 8
+move 15 from 9 to 2
+move 1 from 3 to 9
+move 10 from 6 to 8
+move 1 from 4 to 9
+move 1 from 8 to 3
+move 1 from 4 to 6
+move 4 from 6 to 3
+move 2 from 9 to 7
+move 1 from 7 to 6
+move 1 from 1 to 6
+move 3 from 3 to 8
+move 2 from 7 to 8
+move 3 from 8 to 4
+move 12 from 2 to 9
+move 14 from 9 to 5
+move 12 from 8 to 2
+move 1 from 6 to 7
+move 8 from 3 to 1
+move 2 from 4 to 6
+move 1 from 3 to 6
+move 5 from 6 to 1
+move 17 from 1 to 2
+move 29 from 2 to 1
+move 1 from 8 to 5
+move 1 from 4 to 3
+move 1 from 8 to 5
+move 1 from 8 to 7
+move 5 from 2 to 1
+move 1 from 3 to 5
+move 1 from 6 to 4
+move 6 from 5 to 8
+move 1 from 4 to 9
+move 1 from 7 to 2
+move 1 from 2 to 6
+move 7 from 8 to 7
+move 1 from 6 to 9
+move 2 from 9 to 2
+move 2 from 2 to 8
+move 15 from 1 to 2
+move 2 from 8 to 3
+move 9 from 1 to 2
+move 24 from 2 to 7
+move 11 from 1 to 2
+move 1 from 3 to 1
+move 22 from 7 to 6
+move 6 from 5 to 2
+move 2 from 6 to 5
+move 1 from 1 to 9
+move 1 from 9 to 6
+move 6 from 5 to 1
+move 12 from 6 to 2
+move 3 from 1 to 5
+move 1 from 3 to 2
+move 25 from 2 to 6
+move 4 from 7 to 5
+move 8 from 5 to 4
+move 4 from 4 to 8
+move 1 from 1 to 8
+move 5 from 8 to 4
+move 4 from 4 to 1
+move 2 from 1 to 9
+move 20 from 6 to 8
+move 4 from 2 to 6
+move 19 from 8 to 7
+move 2 from 9 to 3
+move 1 from 8 to 2
+move 11 from 6 to 7
+move 3 from 1 to 2
+move 5 from 4 to 3
+move 1 from 1 to 3
+move 1 from 3 to 5
+move 2 from 2 to 8
+move 33 from 7 to 3
+move 1 from 5 to 3
+move 1 from 8 to 7
+move 1 from 7 to 4
+move 5 from 6 to 8
+move 2 from 7 to 6
+move 2 from 2 to 3
+move 1 from 2 to 5
+move 1 from 7 to 9
+move 1 from 5 to 7
+move 1 from 8 to 2
+move 1 from 4 to 3
+move 43 from 3 to 7
+move 1 from 3 to 8
+move 1 from 6 to 8
+move 8 from 7 to 5
+move 3 from 5 to 3
+move 1 from 6 to 4
+move 2 from 6 to 7
+move 4 from 8 to 7
+move 3 from 3 to 2
+move 1 from 9 to 6
+move 3 from 8 to 3
+move 1 from 6 to 7
+move 1 from 4 to 6
+move 1 from 3 to 7
+move 1 from 3 to 2
+move 5 from 2 to 5
+move 1 from 6 to 1
+move 1 from 3 to 2
+move 42 from 7 to 5
+move 44 from 5 to 4
+move 2 from 5 to 8
+move 1 from 7 to 3
+move 16 from 4 to 6
+move 3 from 5 to 9"#;
+
+#[cfg(test)]
+pub(crate) const TEST: &str = r#"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_sample() -> Result<()> {
+        assert_eq!(part_1(&parse(TEST)?)?, "CMZ");
+        Ok(())
+    }
+
+    #[test]
+    fn part_2_sample() -> Result<()> {
+        assert_eq!(part_2(&parse(TEST)?)?, "MCD");
+        Ok(())
+    }
+
+    #[test]
+    fn impossible_procedure() -> Result<()> {
+        let input = parse(&format!("{}\nmove 5 from 3 to 1", TEST))?;
+        assert_eq!(
+            part_1(&input).unwrap_err().to_string(),
+            "move 5: stack 3 has 4 crate(s), needs 5"
+        );
+        assert!(dry_run(&input.0, &input.1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn no_such_stack() {
+        let error = |m: &str| parse(&format!("{}\n{}", TEST, m)).unwrap_err().to_string();
+        assert_eq!(
+            error("move 1 from 12 to 1"),
+            "line 10, column 13: there are only 3 stacks: \"move 1 from 12 to 1\""
+        );
+        assert_eq!(
+            error("move 1 from 2 to 4"),
+            "line 10, column 18: there are only 3 stacks: \"move 1 from 2 to 4\""
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{draw, Crane, Move, MoveError};
+
+/// The stacks before and after every move of a procedure, to step back and forth through.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    moves: Vec<Move>,
+    /// The stacks after each number of moves, from none to all of them.
+    states: Vec<Vec<Vec<char>>>,
+    position: usize,
+}
+
+impl Replay {
+    /// Has `crane` carry out `procedure` on a copy of `stacks`, keeping every state along the
+    /// way. Starts before the first move.
+    pub fn record(
+        crane: &mut impl Crane,
+        stacks: &[Vec<char>],
+        procedure: &[Move],
+    ) -> Result<Replay, MoveError> {
+        let mut states = Vec::with_capacity(procedure.len() + 1);
+        let mut current = stacks.to_vec();
+        states.push(current.clone());
+        for (i, m) in procedure.iter().enumerate() {
+            crane
+                .try_exec(m, &mut current)
+                .map_err(|reason| MoveError {
+                    number: i + 1,
+                    reason,
+                })?;
+            states.push(current.clone());
+        }
+        Ok(Replay {
+            moves: procedure.to_vec(),
+            states,
+            position: 0,
+        })
+    }
+
+    /// How many moves the procedure has.
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    /// How many moves have been made so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The stacks after the moves made so far.
+    pub fn stacks(&self) -> &[Vec<char>] {
+        &self.states[self.position]
+    }
+
+    /// The move that got the stacks to where they are, unless none has been made yet.
+    pub fn last_move(&self) -> Option<&Move> {
+        self.position.checked_sub(1).map(|i| &self.moves[i])
+    }
+
+    /// Makes the next move, unless they've all been made.
+    pub fn forward(&mut self) -> bool {
+        self.jump(self.position + 1)
+    }
+
+    /// Takes back the last move, unless none has been made.
+    pub fn back(&mut self) -> bool {
+        self.position > 0 && self.jump(self.position - 1)
+    }
+
+    /// Goes to where the stacks are after the first `n` moves, if the procedure has that many.
+    pub fn jump(&mut self, n: usize) -> bool {
+        if n > self.len() {
+            return false;
+        }
+        self.position = n;
+        true
+    }
+
+    /// The pictures showing move `n`, numbered from 1, being made: the stacks before it, its
+    /// crates lifted over the stack they came from, then over the one they're going to, then
+    /// the stacks after it. `None` if there's no such move.
+    pub fn frames(&self, n: usize) -> Option<Vec<String>> {
+        let m = self.moves.get(n.checked_sub(1)?)?;
+        let (before, after) = (&self.states[n - 1], &self.states[n]);
+        // The load as it lands, which depends on the crane.
+        let load = &after[m.dest][after[m.dest].len() - m.quantity..];
+        let mut lifted = before.clone();
+        lifted[m.src].truncate(before[m.src].len() - m.quantity);
+        Some(vec![
+            draw(before),
+            hovering(&lifted, load, m.src),
+            hovering(&lifted, load, m.dest),
+            draw(after),
+        ])
+    }
+}
+
+/// Draws `stacks` with `load` held in the air above stack `column`, counted from 0.
+fn hovering(stacks: &[Vec<char>], load: &[char], column: usize) -> String {
+    let width = (stacks.len() * 4).saturating_sub(1);
+    let mut rows: Vec<String> = load
+        .iter()
+        .rev()
+        .map(|ch| {
+            let crate_ = format!("{}[{}]", " ".repeat(column * 4), ch);
+            format!("{:width$}", crate_, width = width)
+        })
+        .collect();
+    rows.push(" ".repeat(width));
+    rows.push(draw(stacks));
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, CrateMover9001, TEST};
+    use anyhow::Result;
+
+    fn replay() -> Result<Replay> {
+        let (stacks, moves) = parse(TEST)?;
+        Ok(Replay::record(&mut CrateMover9001, &stacks, &moves)?)
+    }
+
+    #[test]
+    fn stepping() -> Result<()> {
+        let mut replay = replay()?;
+        assert_eq!(replay.len(), 4);
+        assert_eq!(replay.last_move(), None);
+        assert!(!replay.back());
+
+        assert!(replay.forward());
+        assert_eq!(
+            replay.stacks(),
+            [vec!['Z', 'N', 'D'], vec!['M', 'C'], vec!['P']]
+        );
+        assert_eq!(
+            replay.last_move().unwrap().to_string(),
+            "move 1 from 2 to 1"
+        );
+
+        assert!(replay.jump(4));
+        assert!(!replay.forward());
+        assert_eq!(crate::tops(replay.stacks()), "MCD");
+        assert!(replay.back());
+        assert_eq!(replay.position(), 3);
+        assert!(!replay.jump(5));
+        assert_eq!(replay.position(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn animation() -> Result<()> {
+        let replay = replay()?;
+        let frames = replay.frames(2).unwrap();
+        assert_eq!(frames.len(), 4);
+        assert_eq!(
+            frames[0],
+            "[D]        \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 "
+        );
+        assert_eq!(
+            frames[2],
+            "        [D]\n        [N]\n        [Z]\n           \n    [C]    \n    [M] [P]\n 1   2   3 "
+        );
+        assert_eq!(
+            frames[3],
+            "        [D]\n        [N]\n    [C] [Z]\n    [M] [P]\n 1   2   3 "
+        );
+        assert_eq!(replay.frames(0), None);
+        assert_eq!(replay.frames(5), None);
+        Ok(())
+    }
+}
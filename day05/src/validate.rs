@@ -0,0 +1,126 @@
+use thiserror::Error;
+
+use crate::Move;
+
+/// Why a move can't be carried out. Stacks are numbered from 1, as in the drawing.
+#[derive(Debug, Error, Clone, Eq, PartialEq)]
+pub enum Invalid {
+    #[error("there's no stack {stack}, only {stacks}")]
+    NoSuchStack { stack: usize, stacks: usize },
+    #[error("stack {stack} is both the source and the destination")]
+    SameStack { stack: usize },
+    #[error("stack {stack} has {held} crate(s), needs {quantity}")]
+    TooFewCrates {
+        stack: usize,
+        held: usize,
+        quantity: usize,
+    },
+}
+
+/// A move of a procedure that can't be carried out, numbered from 1.
+#[derive(Debug, Error, Clone, Eq, PartialEq)]
+#[error("move {number}: {reason}")]
+pub struct MoveError {
+    pub number: usize,
+    pub reason: Invalid,
+}
+
+impl Move {
+    /// Checks that the move could be carried out on `stacks`.
+    pub fn validate(&self, stacks: &[Vec<char>]) -> Result<(), Invalid> {
+        self.validate_heights(&heights(stacks))
+    }
+
+    /// Checks the move against just how many crates each stack holds, which is all that
+    /// decides whether it can be carried out.
+    fn validate_heights(&self, heights: &[usize]) -> Result<(), Invalid> {
+        for stack in [self.src, self.dest] {
+            if stack >= heights.len() {
+                return Err(Invalid::NoSuchStack {
+                    stack: stack + 1,
+                    stacks: heights.len(),
+                });
+            }
+        }
+        if self.src == self.dest {
+            return Err(Invalid::SameStack {
+                stack: self.src + 1,
+            });
+        }
+        if heights[self.src] < self.quantity {
+            return Err(Invalid::TooFewCrates {
+                stack: self.src + 1,
+                held: heights[self.src],
+                quantity: self.quantity,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn heights(stacks: &[Vec<char>]) -> Vec<usize> {
+    stacks.iter().map(Vec::len).collect()
+}
+
+/// Checks a whole procedure against `stacks` without moving any crates, reporting the first
+/// move that couldn't be carried out. Every crane moves the same number of crates between the
+/// same stacks, so a procedure that passes works with any of them.
+pub fn dry_run(stacks: &[Vec<char>], procedure: &[Move]) -> Result<(), MoveError> {
+    let mut heights = heights(stacks);
+    for (i, m) in procedure.iter().enumerate() {
+        m.validate_heights(&heights).map_err(|reason| MoveError {
+            number: i + 1,
+            reason,
+        })?;
+        heights[m.src] -= m.quantity;
+        heights[m.dest] += m.quantity;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mv(quantity: usize, src: usize, dest: usize) -> Move {
+        Move {
+            quantity,
+            src,
+            dest,
+        }
+    }
+
+    #[test]
+    fn invalid_moves() {
+        let stacks = vec![vec!['A', 'B'], vec!['C']];
+        assert_eq!(mv(2, 0, 1).validate(&stacks), Ok(()));
+        assert_eq!(
+            mv(2, 1, 0).validate(&stacks),
+            Err(Invalid::TooFewCrates {
+                stack: 2,
+                held: 1,
+                quantity: 2
+            })
+        );
+        assert_eq!(
+            mv(1, 0, 0).validate(&stacks),
+            Err(Invalid::SameStack { stack: 1 })
+        );
+        assert_eq!(
+            mv(1, 0, 2).validate(&stacks),
+            Err(Invalid::NoSuchStack {
+                stack: 3,
+                stacks: 2
+            })
+        );
+    }
+
+    #[test]
+    fn dry_runs() {
+        let stacks = vec![vec!['A', 'B'], vec!['C']];
+        assert_eq!(dry_run(&stacks, &[mv(2, 0, 1), mv(3, 1, 0)]), Ok(()));
+
+        let err = dry_run(&stacks, &[mv(2, 0, 1), mv(1, 0, 1)]).unwrap_err();
+        assert_eq!(err.to_string(), "move 2: stack 1 has 0 crate(s), needs 1");
+    }
+}
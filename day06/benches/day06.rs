@@ -0,0 +1 @@
+aoc_common::bench_day!(6, day06);
@@ -0,0 +1,221 @@
+use std::io::{self, Read};
+
+use anyhow::{Error, Result};
+
+pub fn parse(input: &str) -> Result<Vec<u8>> {
+    Ok(input.as_bytes().to_vec())
+}
+
+pub fn part_1(signal: &[u8]) -> Result<u64> {
+    find_marker(signal, 4)?.ok_or_else(|| Error::msg("No start-of-packet marker"))
+}
+
+pub fn part_2(signal: &[u8]) -> Result<u64> {
+    find_marker(signal, 14)?.ok_or_else(|| Error::msg("No start-of-message marker"))
+}
+
+/// Watches a signal a byte at a time for the first `window` bytes in a row that are all
+/// different.
+///
+/// It counts how often each byte value appears in the window, and how many values appear at
+/// all, so each byte takes the same time however wide the window is.
+#[derive(Debug, Clone)]
+pub struct Detector {
+    window: usize,
+    /// The last `window` bytes, the oldest at `oldest` once it's full.
+    recent: Vec<u8>,
+    oldest: usize,
+    counts: [usize; 256],
+    distinct: usize,
+    read: u64,
+    marker: Option<u64>,
+}
+
+impl Detector {
+    /// Panics if `window` is 0.
+    pub fn new(window: usize) -> Detector {
+        assert!(window > 0, "a marker needs at least one byte");
+        Detector {
+            window,
+            // A window wider than 256 bytes never finds a marker, so only grows as it fills.
+            recent: Vec::with_capacity(window.min(256)),
+            oldest: 0,
+            counts: [0; 256],
+            distinct: 0,
+            read: 0,
+            marker: None,
+        }
+    }
+
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// How many bytes of the signal it has taken.
+    pub fn read(&self) -> u64 {
+        self.read
+    }
+
+    /// How many bytes had been read when the last `window` were first all different, if they
+    /// have been yet.
+    pub fn marker(&self) -> Option<u64> {
+        self.marker
+    }
+
+    /// Takes the next byte of the signal, and returns whether the last `window` bytes are now
+    /// all different.
+    pub fn push(&mut self, byte: u8) -> bool {
+        if self.recent.len() < self.window {
+            self.recent.push(byte);
+        } else {
+            let old = std::mem::replace(&mut self.recent[self.oldest], byte);
+            self.oldest = (self.oldest + 1) % self.window;
+            self.counts[old as usize] -= 1;
+            if self.counts[old as usize] == 0 {
+                self.distinct -= 1;
+            }
+        }
+        self.counts[byte as usize] += 1;
+        if self.counts[byte as usize] == 1 {
+            self.distinct += 1;
+        }
+        self.read += 1;
+
+        let unique = self.distinct == self.window;
+        if unique && self.marker.is_none() {
+            self.marker = Some(self.read);
+        }
+        unique
+    }
+}
+
+/// How much of the signal is read at once.
+const CHUNK: usize = 64 * 1024;
+
+/// The first marker for each of `windows`, as [`Detector::marker`] gives them, in one pass
+/// over `reader`. Only a chunk of the signal is held in memory at a time, and reading stops
+/// once every marker is found.
+///
+/// The signal is read as bytes, which are its characters as long as it's ASCII. Line breaks
+/// aren't part of it, and are skipped. A window of no bytes, or of more than there are byte
+/// values, never has a marker.
+pub fn find_markers<R: Read>(mut reader: R, windows: &[usize]) -> io::Result<Vec<Option<u64>>> {
+    let mut detectors: Vec<Option<Detector>> = windows
+        .iter()
+        .map(|&w| (1..=256).contains(&w).then(|| Detector::new(w)))
+        .collect();
+    let mut buf = vec![0; CHUNK];
+    while detectors.iter().flatten().any(|d| d.marker().is_none()) {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        let signal = buf[..n].iter().filter(|&&b| b != b'\n' && b != b'\r');
+        for &byte in signal {
+            for d in detectors
+                .iter_mut()
+                .flatten()
+                .filter(|d| d.marker().is_none())
+            {
+                d.push(byte);
+            }
+        }
+    }
+    Ok(detectors
+        .iter()
+        .map(|d| d.as_ref().and_then(Detector::marker))
+        .collect())
+}
+
+/// The first marker `window` bytes wide in `reader`; see [`find_markers`].
+pub fn find_marker<R: Read>(reader: R, window: usize) -> io::Result<Option<u64>> {
+    Ok(find_markers(reader, &[window])?[0])
+}
+
+pub const INPUT: &str = r#"mnlnvlljqqccznnjtjljbllrtllwwpmmhjjbbzppnndmmsppdqqwvvstvssgmsggmlmttnvvfbbdsssnzzbssjrsjjpmpvmmcjjwsssndsslwsswtwnwrrslshhvzzsppffmpfmmfvfpfpsssqpqzpqqcjcjnjcnnzbzjzpzbpbnbwbcctvvhgvgsvvpwwvjjvqjjjdqqrmrmqmsqszqsqpsqslsddhbhcbhbchcvvjvjcjnccdbcdcrddldblbffhvffpvpzpvvmvfmmwhwqhqvhqhmmpdmmlbmbgmbbrqqpmqqcvcmvvcncllptltvtdtbbqzzcggjgsjjvjsvvgmgffqhqqgpptspsffvdvbbhqhzzllvvjbvvbpppggfpgptgtvvzdvdzdgzgccmmphpmhppldlnlpnnhghhrrgwrwssnllmpllbvbvqvtvhtvvmnvvpgvgfvggtztpthhcfhfqfhhnhtnhhljhjppqjjffgjggrwrjjhphzhtztggwswnwzzvbzzmmtrtqtjqttwlwmmmmnddmnddwvvcllgrgfgzznwnsswjjhwwspsbbvzzqvzvbvcvmmtltnlnfnfnwwsvwwpswppjhjdhhmbbblfbfwffwvvjgghwhzhjzzrttwhwjhjchjhggdrgdgmmsjsfstftvtmtctggcwgcgzccgzgsgrgmmjhhqzzrmrttgtgfgcffvsfvvslsvvpqvvnjnrrwdwcwcnnhllwpwdpwdpdqqtwtftdftddppncpnccllqqrffpssgvsvtvmvssrbrhbbzggtssdsvddqfdfjfhjhdjhhncnddfpdfdmmrddncnvcnvccgvvhzvzwztwzwtzwtwqttrlrvrddztzrzcccgmmqgqjgqjgjqqspqqpjppbggchcqcpqqgbgdbbspbbrbhrhzhqhrqhhhtbhhvshspsvsggjdjwjwvjvdvwddjggmrrbnrrztthlllhlclbclbbhpbhphjhccdwccdbbjrbjjmrjrhhnlnjllltwltlmmlqlnqntqnntsnsqqvtvwvgwvwnncgcdctddnttfjfqfttrhrjhjqqcnnsmnmgmqgmgbblcctntrntnccnvvmpmjjvfjfrrbpbttsbttvnttmnnjdnndnzntnrnwrwcctllvhvqhhddmzztppphghphzzglzlnnfccrfffvvhllpspwssstwstsvttcrtccfssbccdjdqdfqddrbrqbbtllmmsfmfcmmzwzpznpnttjgtgbbdtdvdwwpmphpprsrjrbrqbqwwljlslrlrhhpchpcprcrtcrcfcfssndsspddcjjjfmfqfggmssnhsnhnpncnfnmffdrdjdhjjrgjrjgjqgjqqlmlljffbcfcrrrzwwftwtrrpgpprqqmrmhmwhwmmcrrhqrqwqppwjwggpdpgpvgvzvttqlljhhbvbhblhhcsssvmsmppcvpvrvzvbvtbtssplpgptgtnthhvwhvwwfvvfwwmtwmwfmfgmgnmnllgsgmsgswwhqhhhzqhhfwwnttmfmrrfnnpbpssvbsshqhqvhqqbmbpmbmqqjtjqjvjtjjhtttpzphpqqwqfqttqqhfhbfhfwwcpcpssdvvzhzwwqddjzdjdldlggvnvlnlbljlqjjmcczbccznnlnslswlwplpttvrvllfwflftllhclldhlhddbvvpvzpvpmmrccvgvdvqqjcqqwvvnjjlbbjwjrjhhlzhlhttljlcjjsnsgngrnntzzbsbmbsbrbdrdppjrjlrrjljqlqhqqnqsgdvhpgdhmnslqtjclmcfzrmgmlfnjbzznfgfprvwprwdbcgfcclmspgnzpbshwjbqvhzhrhswjzbfvnmcjtfvqbwmjpvfvctpmwsspdbtvfhfdfzjdpqnvslgmdvrnflzwzcnzmvzsvznwhpwtjwnqdgrrttmmdwzbbnwtllpbffrgtpjjjwltqrcbqcttdwnfjpmhdsbbpqmstjqchgjvfrmrbgqrlstnbdnzzzbzbsmsnnsssswmqhcbswtjhmcgnwmcclhzjqjzqcpbzgdzjgqzpqbmvvhtcznfrhdndswfvfhtfpdpszpjqrlwfdscvcngftwqmfttjtjrlbgcwvcjwsstqmcblmjzsgtgrqnqqvhzhvsphjmbcpfcznlcqldcvhlsvggbjngmhspwwqhlwstslvwmmbwqdmrgdvvnlstmjllhzscrhzjtmnsjfbndnlmzqbzgdgbcqchnbvwsftjtznnbsnvsgzpdzdqznjsslrlfnccdhwsljhczggvmgqswjltmrqqmwtbzmtdzhpjcvmwsscsdzpfnwlcrrdgzqqdmgwdlzvvvjcqsgpcwvrdnrstpcmgfjnjffbfmgzjthhllzrlsjtnqfppltbrlnqnjvqlvtpqvsbfgmmlcdzhgmzzqjwtqtzmpwwddbqrqnfzzpsjglsjddsslwwlrttzfzplmwsswlnvrvwwcgddjwcmvsjjbfgcfjmthfbpmcwjptchhnsmzttjqnwzdljffghhqdcwzwgbvfsmwqdbtblphdgcmbhprtbccjbzqrpvjdbnsmlwfntvjgptnshzmddwbhgwsnfrjbpqqwlsfdpnmmnnwhdmhzvjcmddbdnjzfzvffbgdqgwbggprcrbzwhvtzzgbhhcscrlmfgztfswjbsnwsmdfwlntwjzvlwhvlrfzszllmflmrsrcfnncvszvgdmmnvgrqnjhljcnrrhpdhffwmrsqfnbcpfdmmgppwjbjrwdfmpcrbznrjnbmssszhnlbpgmlczhhcdtgjqcbqrvzcbpgrftfhzdqthhspwnqqswntlpcmmqtcszngpggqvfjnmnprhdfjsngwrncjcmqdmjhpdlfnshpdlnlfpcnprwjgdvwwbvhvsbrfjtqsqjnvcpfdsrnfwmrrbtcvcqzflhdlbpcthzthdjzsrvwgbhjvhbtrngthfrszlvrbtnscsqlblcwlngslspcrhqzzdlzcdbhqdhthlpmdrntbhnqtwtzwpndbgphpsllbvgqjtmszdvjpgttzcmbwgrgdwmsbfgvgbbcmsnhvmnsbcsthsdwdqtghpdclfbglbdjgnnnwhmzzvnhbmgfbmvqwvwqhdswgtzslspmbmznnwdmjbzbddhzchtdzdzgwtlmlpmwrqvghpfwhvfjrtvmjwgjjnwdwnpdcqjdmcctjfcrdgpvczvnhlrbfmqgnrhmdwsrmmpqhvwgqgbqccpznpjfldwpntnvzgdfzljmtqwvfnrdsjsqgbvzjsczwwjggqtrpvwgqggwwhqggtgqfjmzsmjvdhdwqggbgnftpqqqlsfpflwrdpjwnhfdpchcgntjshgtwnwrnpsvwmplvqcltbgrcpflpgzbqfclghfnwjchnbgjnplgldmphdplvjrnrtzcmlftprsnmrjmnffpqjlvqlztbwprjwprrmmgzhjgdnhbfdrwjtvsvnbqhtfhbqgdrvcwlwfdbbcthgvttpvrwrqmpmrmvgpjzwlpvbqcvgccpgfddjbwhrvgqmjqzwgghllrtrblcpbttmcrgjsftlqhjfvqnbhmhbhngwnfqtgdttstzvmstrqcpfjrdgtsdbqqccqvbhpwhnpmpqgntfqszndjrmfhlqjqjbqvjtlfmrnnzzrtqlzzhjfqmmsmzvzrcplmfjpcmpfmpzbsbrmbnbnjqwjcfwnnwwrwzvvrsvhvrwnhlmwqjdztqthcwnwrlbjdflfsbplbwfmzqqnpwvzjbcfdgztpwttlrvhlfzzsfltpqwcpnzlsqgvwnqfvgclrfvssfcfmfvvjsndrhqdbrqfggfhjbvdvvmgpglqzwgjdmqtscjpfhgsbshghtmftrrhznttpzrzcsmrrvzdjmwtmbcbpqbsdmqzqdzrncwzmptltvdphsltfrhbbrdzbnbsqdhfvrgvmbgfvwblsjvfphlpzfvsllwnqjmbhngzzslcdmdzfgrgscbzggrzmbmwlzbnpzcvsbsfgdpnwzljsf"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sample streams, with their start-of-packet and start-of-message markers.
+    const TESTS: [(&str, u64, u64); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    #[test]
+    fn part_1_samples() -> Result<()> {
+        for (stream, packet, _) in TESTS {
+            assert_eq!(part_1(&parse(stream)?)?, packet, "{}", stream);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn part_2_samples() -> Result<()> {
+        for (stream, _, message) in TESTS {
+            assert_eq!(part_2(&parse(stream)?)?, message, "{}", stream);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn find_marker_counts_past_the_marker() -> Result<()> {
+        assert_eq!(find_marker(&b"aabcd"[..], 4)?, Some(5));
+        assert_eq!(find_marker(&b"aabcd"[..], 1)?, Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn find_marker_without_a_marker() -> Result<()> {
+        assert_eq!(find_marker(&b"abcabcabc"[..], 4)?, None);
+        assert_eq!(find_marker(&b"ab"[..], 3)?, None);
+        // No window wider than every byte value can be all different.
+        assert_eq!(find_marker(&b"abcd"[..], 300)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn find_marker_impossible_windows() -> Result<()> {
+        assert_eq!(find_marker(&b"abcd"[..], 0)?, None);
+        // Too wide to ever match, so nothing is set aside for it.
+        assert_eq!(find_marker(&b"abcd"[..], usize::MAX)?, None);
+        assert_eq!(
+            find_markers(&b"abcd"[..], &[0, 4, usize::MAX])?,
+            vec![None, Some(4), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn markers_across_reads_and_lines() -> Result<()> {
+        let (stream, packet, message) = TESTS[0];
+        let (a, b) = stream.as_bytes().split_at(packet as usize - 2);
+        let reader = a.chain(&b"\r\n"[..]).chain(b);
+        assert_eq!(
+            find_markers(reader, &[4, 14])?,
+            vec![Some(packet), Some(message)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn long_streams() -> Result<()> {
+        let n = 3 * CHUNK as u64;
+        let reader = io::repeat(b'a').take(n).chain(&b"bcd"[..]);
+        assert_eq!(find_marker(reader, 4)?, Some(n + 3));
+
+        let mut detector = Detector::new(2);
+        assert!(!detector.push(b'x'));
+        assert!(detector.push(b'y'));
+        assert!(!detector.push(b'y'));
+        assert_eq!((detector.read(), detector.marker()), (3, Some(2)));
+        Ok(())
+    }
+}
@@ -0,0 +1,484 @@
+use crate::Instruction::{Addx, Noop};
+use anyhow::Result;
+use aoc_common::parse::{self, Located};
+use itertools::Itertools;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("line {line}, column {column}: unknown instruction {text:?}")]
+    UnknownInstruction {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    #[error("line {line}, column {column}: bad addx argument {text:?}")]
+    BadArgument {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+}
+
+impl Located for ParseError {
+    fn below(mut self, lines: usize) -> Self {
+        match &mut self {
+            ParseError::UnknownInstruction { line, .. } | ParseError::BadArgument { line, .. } => {
+                *line += lines
+            }
+        }
+        self
+    }
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(' ') {
+            None if s == "noop" => Ok(Noop),
+            Some(("addx", n)) => n.parse().map(Addx).map_err(|_| ParseError::BadArgument {
+                line: 1,
+                column: parse::column(s, n),
+                text: n.to_string(),
+            }),
+            _ => Err(ParseError::UnknownInstruction {
+                line: 1,
+                column: 1,
+                text: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// The value of the X register during each cycle of `program`, starting from cycle 1.
+pub fn run_log(program: &[Instruction]) -> Vec<i32> {
+    let mut x = 1;
+    let mut log = Vec::new();
+
+    for ins in program {
+        match ins {
+            Noop => {
+                log.push(x);
+            }
+            Addx(n) => {
+                log.push(x);
+                log.push(x);
+                x += n;
+            }
+        }
+    }
+
+    log.push(x);
+    log
+}
+
+/// The sum of the signal strengths during each of `clocks`, which must all be within `log`.
+pub fn signal_str(log: &[i32], clocks: &[usize]) -> i32 {
+    clocks.iter().map(|&t| log[t - 1] * (t as i32)).sum()
+}
+
+/// What the CRT draws, a row of `width` pixels at a time.
+pub fn print_image(log: &[i32], width: usize) -> String {
+    log.iter()
+        .enumerate()
+        .map(|(px, &sprite_pos)| {
+            if sprite_pos > 0 {
+                let pos: usize = sprite_pos.try_into().unwrap();
+                if (pos - 1..=pos + 1).contains(&(px % 40)) {
+                    '#'
+                } else {
+                    '.'
+                }
+            } else {
+                '.'
+            }
+        })
+        .chunks(width)
+        .into_iter()
+        .map(|ss| ss.collect::<String>())
+        .join("\n")
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::lines(input)
+}
+
+pub fn part_1(input: &[Instruction]) -> Result<i32> {
+    let log = run_log(input);
+
+    Ok(signal_str(&log, &[20, 60, 100, 140, 180, 220]))
+}
+
+pub fn part_2(input: &[Instruction]) -> Result<String> {
+    let log = run_log(input);
+
+    Ok(print_image(&log, 40))
+}
+
+pub const INPUT: &str = r#"addx 1
+noop
+addx 2
+addx 11
+addx -4
+noop
+noop
+noop
+noop
+addx 3
+addx -3
+addx 10
+addx 1
+noop
+addx 12
+addx -8
+addx 5
+noop
+noop
+addx 1
+addx 4
+addx -12
+noop
+addx -25
+addx 14
+addx -7
+noop
+addx 11
+noop
+addx -6
+addx 3
+noop
+addx 2
+addx 22
+addx -12
+addx -17
+addx 15
+addx 2
+addx 10
+addx -9
+noop
+noop
+noop
+addx 5
+addx 2
+addx -33
+noop
+noop
+noop
+noop
+addx 12
+addx -9
+addx 7
+noop
+noop
+addx 3
+addx -2
+addx 2
+addx 26
+addx -31
+addx 14
+addx 3
+noop
+addx 13
+addx -1
+noop
+addx -5
+addx -13
+addx 14
+noop
+addx -20
+addx -15
+noop
+addx 7
+noop
+addx 31
+noop
+addx -26
+noop
+noop
+noop
+addx 5
+addx 20
+addx -11
+addx -3
+addx 9
+addx -5
+addx 2
+noop
+addx 4
+noop
+addx 4
+noop
+noop
+addx -7
+addx -30
+noop
+addx 7
+noop
+noop
+addx -2
+addx -4
+addx 11
+addx 14
+addx -9
+addx -2
+noop
+addx 7
+noop
+addx -11
+addx -5
+addx 19
+addx 5
+addx 2
+addx 5
+noop
+noop
+addx -2
+addx -27
+addx -6
+addx 1
+noop
+noop
+addx 4
+addx 1
+addx 4
+addx 5
+noop
+noop
+noop
+addx 1
+noop
+addx 4
+addx 1
+noop
+noop
+addx 5
+noop
+noop
+addx 4
+addx 1
+noop
+addx 4
+addx 1
+noop
+noop
+noop
+noop"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The small example program from the puzzle text.
+    const TEST: &str = r#"noop
+addx 3
+addx -5"#;
+
+    /// The larger example program from the puzzle text.
+    const LARGER: &str = r#"addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop"#;
+
+    #[test]
+    fn run_log_sample() -> Result<()> {
+        // x during cycles 1-5, then after the last instruction
+        assert_eq!(run_log(&parse(TEST)?), vec![1, 1, 1, 4, 4, -1]);
+        Ok(())
+    }
+
+    #[test]
+    fn signal_str_weights_by_cycle() {
+        let log = [1, 2, 3, 4];
+        assert_eq!(signal_str(&log, &[2, 4]), 2 * 2 + 4 * 4);
+    }
+
+    #[test]
+    fn part_1_sample() -> Result<()> {
+        assert_eq!(part_1(&parse(LARGER)?)?, 13140);
+        Ok(())
+    }
+
+    #[test]
+    fn part_2_sample() -> Result<()> {
+        let image = part_2(&parse(LARGER)?)?;
+        let rows: Vec<&str> = image.lines().take(6).collect();
+        assert_eq!(
+            rows,
+            [
+                "##..##..##..##..##..##..##..##..##..##..",
+                "###...###...###...###...###...###...###.",
+                "####....####....####....####....####....",
+                "#####.....#####.....#####.....#####.....",
+                "######......######......######......####",
+                "#######.......#######.......#######.....",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn part_1_input() -> Result<()> {
+        assert_eq!(part_1(&parse(INPUT)?)?, 14780);
+        Ok(())
+    }
+
+    #[test]
+    fn part_2_input() -> Result<()> {
+        let image = part_2(&parse(INPUT)?)?;
+        assert_eq!(
+            image.lines().next(),
+            Some("####.#....###..#....####..##..####.#....")
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,356 @@
+use anyhow::Result;
+use aoc_common::parse::{self, Located};
+use itertools::Itertools;
+use std::cell::RefCell;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("line {line}, column {column}: expected {expected:?}, found {text:?}")]
+    MissingField {
+        line: usize,
+        column: usize,
+        text: String,
+        expected: &'static str,
+    },
+    #[error("line {line}, column {column}: bad number {text:?}")]
+    BadNumber {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    #[error("line {line}, column {column}: expected an operation like \"* 19\", found {text:?}")]
+    BadOperation {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    #[error("line {line}, column {column}: a monkey must throw to another monkey, found {text:?}")]
+    BadTarget {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+}
+
+impl Located for ParseError {
+    fn below(mut self, lines: usize) -> Self {
+        match &mut self {
+            ParseError::MissingField { line, .. }
+            | ParseError::BadNumber { line, .. }
+            | ParseError::BadOperation { line, .. }
+            | ParseError::BadTarget { line, .. } => *line += lines,
+        }
+        self
+    }
+}
+
+/// How a monkey changes an item's worry level when it inspects it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Op {
+    Add(usize),
+    Mul(usize),
+    Square,
+}
+
+impl Op {
+    pub fn exec(&self, old: usize) -> usize {
+        match self {
+            Op::Add(n) => old + n,
+            Op::Mul(n) => old * n,
+            Op::Square => old * old,
+        }
+    }
+}
+
+/// A monkey's held items, as worry levels, and the rules it follows. Targets are indexes of
+/// other monkeys.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub items: Vec<usize>,
+    pub op: Op,
+    pub modulus: usize,
+    pub true_target: usize,
+    pub false_target: usize,
+}
+
+/// The rest of line `i` of a monkey's description, after `prefix`.
+fn field<'a>(lines: &[&'a str], i: usize, prefix: &'static str) -> Result<&'a str, ParseError> {
+    let l = lines.get(i).copied().unwrap_or_default();
+    l.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::MissingField {
+            line: i + 1,
+            column: 1,
+            text: l.to_string(),
+            expected: prefix,
+        })
+}
+
+fn number(lines: &[&str], i: usize, n: &str) -> Result<usize, ParseError> {
+    n.parse().map_err(|_| ParseError::BadNumber {
+        line: i + 1,
+        column: parse::column(lines[i], n),
+        text: n.to_string(),
+    })
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+
+        field(&lines, 0, "Monkey ")?;
+        let items = field(&lines, 1, "  Starting items: ")?
+            .split(", ")
+            .map(|item_str| number(&lines, 1, item_str))
+            .collect::<Result<_, _>>()?;
+
+        let op_str = field(&lines, 2, "  Operation: new = old ")?;
+        let op = match op_str.split_once(' ') {
+            Some(("*", "old")) => Op::Square,
+            Some(("*", n)) => Op::Mul(number(&lines, 2, n)?),
+            Some(("+", n)) => Op::Add(number(&lines, 2, n)?),
+            _ => {
+                return Err(ParseError::BadOperation {
+                    line: 3,
+                    column: parse::column(lines[2], op_str),
+                    text: op_str.to_string(),
+                })
+            }
+        };
+
+        let modulus = field(&lines, 3, "  Test: divisible by ")?;
+        let true_target = field(&lines, 4, "    If true: throw to monkey ")?;
+        let false_target = field(&lines, 5, "    If false: throw to monkey ")?;
+
+        Ok(Monkey {
+            items,
+            op,
+            modulus: number(&lines, 3, modulus)?,
+            true_target: number(&lines, 4, true_target)?,
+            false_target: number(&lines, 5, false_target)?,
+        })
+    }
+}
+
+/// The monkeys playing keep away, and how many items each has inspected so far.
+#[derive(Debug, Clone)]
+pub struct Forest {
+    monkeys: Vec<RefCell<Monkey>>,
+    inspection_counts: Vec<usize>,
+    very_worried: bool,
+    test_modulus: usize,
+}
+
+impl Forest {
+    /// Starts a game between `monkeys`. Every target must be the index of some other monkey, as
+    /// [`parse`] checks.
+    ///
+    /// Unless `very_worried`, worry levels are divided by 3 after each inspection.
+    pub fn new(monkeys: &[Monkey], very_worried: bool) -> Forest {
+        let test_modulus = monkeys.iter().map(|m| m.modulus).product();
+
+        Forest {
+            inspection_counts: vec![0; monkeys.len()],
+            monkeys: monkeys.iter().map(|m| RefCell::new(m.clone())).collect(),
+            very_worried,
+            test_modulus,
+        }
+    }
+
+    /// Gives every monkey a turn, in order.
+    pub fn round(&mut self) {
+        for i in 0..self.monkeys.len() {
+            self.turn(i);
+        }
+    }
+
+    fn turn(&mut self, id: usize) {
+        let mut m = self.monkeys[id].borrow_mut();
+        let mut t = self.monkeys[m.true_target].borrow_mut();
+        let mut f = self.monkeys[m.false_target].borrow_mut();
+        for item in m.items.iter() {
+            //inspect
+            self.inspection_counts[id] += 1;
+            let mut new_worry = m.op.exec(*item);
+            if self.very_worried {
+                new_worry %= self.test_modulus;
+            } else {
+                new_worry /= 3;
+            }
+            //test
+            if new_worry.is_multiple_of(m.modulus) {
+                //throw
+                t.items.push(new_worry);
+            } else {
+                //throw
+                f.items.push(new_worry);
+            }
+        }
+        m.items.clear();
+    }
+
+    pub fn inspection_counts(&self) -> &[usize] {
+        &self.inspection_counts
+    }
+
+    /// The product of the two highest inspection counts.
+    pub fn monkey_business(&self) -> usize {
+        self.inspection_counts
+            .iter()
+            .sorted()
+            .rev()
+            .take(2)
+            .product()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkeys = Vec::new();
+    let mut offsets = Vec::new();
+    let mut offset = 0;
+    for block in input.split("\n\n") {
+        let monkey: Monkey = block.parse().map_err(|e: ParseError| e.below(offset))?;
+        monkeys.push(monkey);
+        offsets.push(offset);
+        offset += block.lines().count() + 1;
+    }
+
+    // Monkeys throw to each other by index, and never to themselves.
+    for (id, (m, offset)) in monkeys.iter().zip(offsets).enumerate() {
+        for (target, line) in [(m.true_target, 5), (m.false_target, 6)] {
+            if target == id || target >= monkeys.len() {
+                return Err(ParseError::BadTarget {
+                    line: offset + line,
+                    column: 1,
+                    text: target.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(monkeys)
+}
+
+pub fn part_1(input: &[Monkey]) -> Result<usize> {
+    let mut forest = Forest::new(input, false);
+
+    for _ in 0..20 {
+        forest.round();
+    }
+
+    Ok(forest.monkey_business())
+}
+
+pub fn part_2(input: &[Monkey]) -> Result<usize> {
+    let mut forest = Forest::new(input, true);
+
+    for _ in 0..10000 {
+        forest.round();
+    }
+
+    Ok(forest.monkey_business())
+}
+
+pub const INPUT: &str = r#"Monkey 0:
+  Starting items: 57, 58
+  Operation: new = old * 19
+  Test: divisible by 7
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 66, 52, 59, 79, 94, 73
+  Operation: new = old + 1
+  Test: divisible by 19
+    If true: throw to monkey 4
+    If false: throw to monkey 6
+
+Monkey 2:
+  Starting items: 80
+  Operation: new = old + 6
+  Test: divisible by 5
+    If true: throw to monkey 7
+    If false: throw to monkey 5
+
+Monkey 3:
+  Starting items: 82, 81, 68, 66, 71, 83, 75, 97
+  Operation: new = old + 5
+  Test: divisible by 11
+    If true: throw to monkey 5
+    If false: throw to monkey 2
+
+Monkey 4:
+  Starting items: 55, 52, 67, 70, 69, 94, 90
+  Operation: new = old * old
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 3
+
+Monkey 5:
+  Starting items: 69, 85, 89, 91
+  Operation: new = old + 7
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 7
+
+Monkey 6:
+  Starting items: 75, 53, 73, 52, 75
+  Operation: new = old * 7
+  Test: divisible by 2
+    If true: throw to monkey 0
+    If false: throw to monkey 4
+
+Monkey 7:
+  Starting items: 94, 60, 79
+  Operation: new = old + 2
+  Test: divisible by 3
+    If true: throw to monkey 1
+    If false: throw to monkey 6"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = r#"Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1"#;
+
+    #[test]
+    fn part_1_sample() -> Result<()> {
+        assert_eq!(part_1(&parse(TEST)?)?, 10605);
+        Ok(())
+    }
+
+    #[test]
+    fn part_2_sample() -> Result<()> {
+        assert_eq!(part_2(&parse(TEST)?)?, 2713310158);
+        Ok(())
+    }
+}
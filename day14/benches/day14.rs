@@ -0,0 +1 @@
+aoc_common::bench_day!(14, day14);
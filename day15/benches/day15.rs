@@ -0,0 +1 @@
+aoc_common::bench_day!(15, day15, sample_size = 10);
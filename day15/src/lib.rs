@@ -0,0 +1,236 @@
+use anyhow::{Error, Result};
+use aoc_common::parse::{self, Located};
+use aoc_common::{Interval, IntervalSet};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashSet;
+use std::ops::Range;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("line {line}, column {column}: expected a sensor report, found {text:?}")]
+    BadSensor {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    #[error("line {line}, column {column}: bad coordinate {text:?}")]
+    BadNumber {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+}
+
+impl Located for ParseError {
+    fn below(mut self, lines: usize) -> Self {
+        match &mut self {
+            ParseError::BadSensor { line, .. } | ParseError::BadNumber { line, .. } => {
+                *line += lines
+            }
+        }
+        self
+    }
+}
+
+/// A sensor at `pos`, as `[x, y]`, and the closest beacon to it.
+#[derive(Debug)]
+pub struct Sensor {
+    pub pos: [i64; 2],
+    pub beacon: [i64; 2],
+}
+
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+                r"^Sensor at x=([-\d]+), y=([-\d]+): closest beacon is at x=([-\d]+), y=([-\d]+)$"
+            )
+            .unwrap();
+        }
+
+        let caps = RE.captures(s).ok_or_else(|| ParseError::BadSensor {
+            line: 1,
+            column: 1,
+            text: s.to_string(),
+        })?;
+        let coord = |i: usize| -> Result<i64, ParseError> {
+            let m = caps.get(i).unwrap();
+            m.as_str().parse().map_err(|_| ParseError::BadNumber {
+                line: 1,
+                column: m.start() + 1,
+                text: m.as_str().to_string(),
+            })
+        };
+
+        Ok(Sensor {
+            pos: [coord(1)?, coord(2)?],
+            beacon: [coord(3)?, coord(4)?],
+        })
+    }
+}
+
+impl Sensor {
+    fn manh_dist(a: &[i64; 2], b: &[i64; 2]) -> i64 {
+        (a[0] - b[0]).abs() + (a[1] - b[1]).abs()
+    }
+
+    /// Whether this sensor rules out an undetected beacon at `pt`.
+    pub fn cant_be_beacon(&self, pt: &[i64; 2]) -> bool {
+        if pt == &self.pos {
+            true
+        } else if pt == &self.beacon {
+            false
+        } else {
+            let p_dist = Sensor::manh_dist(&self.pos, pt);
+            let b_dist = Sensor::manh_dist(&self.pos, &self.beacon);
+            p_dist <= b_dist
+        }
+    }
+
+    /// Whether `pt` is out of this sensor's range.
+    pub fn could_be_beacon(&self, pt: &[i64; 2]) -> bool {
+        let p_dist = Sensor::manh_dist(&self.pos, pt);
+        let b_dist = Sensor::manh_dist(&self.pos, &self.beacon);
+        p_dist > b_dist
+    }
+
+    /// The part of row `y` in range, if any.
+    pub fn coverage(&self, y: i64) -> Option<Interval> {
+        let reach = Sensor::manh_dist(&self.pos, &self.beacon) - (self.pos[1] - y).abs();
+        Interval::try_new(self.pos[0] - reach, self.pos[0] + reach)
+    }
+
+    /// The points just out of range, with both coordinates within `bounds`.
+    pub fn outline(&self, bounds: Range<i64>) -> impl Iterator<Item = [i64; 2]> + '_ {
+        let r = Sensor::manh_dist(&self.pos, &self.beacon) + 1;
+
+        (-r..=r)
+            .flat_map(move |dx| {
+                let dy = (r - dx).abs();
+                [
+                    [self.pos[0] + dx, self.pos[1] + dy],
+                    [self.pos[0] + dx, self.pos[1] - dy],
+                ]
+            })
+            .filter(move |pt| bounds.contains(&pt[0]) && bounds.contains(&pt[1]))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    parse::lines(input)
+}
+
+/// The positions in row `y` that are in range of some sensor.
+pub fn row_coverage(input: &[Sensor], y: i64) -> IntervalSet {
+    input.iter().filter_map(|s| s.coverage(y)).collect()
+}
+
+/// How many positions in row `y` can't hold a beacon.
+pub fn non_beacon_count(input: &[Sensor], y: i64) -> Result<usize> {
+    if input.is_empty() {
+        return Err(Error::msg("No sensors"));
+    }
+
+    // Every beacon is in range of the sensor that found it, so it's among the covered positions.
+    let beacons: HashSet<i64> = input
+        .iter()
+        .filter(|s| s.beacon[1] == y)
+        .map(|s| s.beacon[0])
+        .collect();
+    let covered = row_coverage(input, y).size() as usize;
+
+    Ok(covered - beacons.len())
+}
+
+/// The tuning frequency of the only place in `0..=limit` on both axes the distress beacon can be.
+pub fn tuning_frequency(input: &[Sensor], limit: i64) -> Result<i64> {
+    let distress_beacon = input
+        .iter()
+        .flat_map(|s| s.outline(0..limit + 1))
+        .find(|pt| input.iter().all(|s| s.could_be_beacon(pt)))
+        .ok_or_else(|| Error::msg("No room for the distress beacon"))?;
+
+    Ok(distress_beacon[0] * 4000000 + distress_beacon[1])
+}
+
+pub fn part_1(input: &[Sensor]) -> Result<usize> {
+    non_beacon_count(input, 2_000_000)
+}
+
+pub fn part_2(input: &[Sensor]) -> Result<i64> {
+    tuning_frequency(input, 4_000_000)
+}
+
+pub const INPUT: &str = r#"Sensor at x=3391837, y=2528277: closest beacon is at x=3448416, y=2478759
+Sensor at x=399473, y=1167503: closest beacon is at x=1188862, y=2000000
+Sensor at x=3769110, y=2896086: closest beacon is at x=4076658, y=2478123
+Sensor at x=900438, y=3835648: closest beacon is at x=-435606, y=3506717
+Sensor at x=2913762, y=3937542: closest beacon is at x=2964244, y=3612685
+Sensor at x=3646459, y=3446878: closest beacon is at x=3264675, y=3635510
+Sensor at x=1182092, y=2135147: closest beacon is at x=1188862, y=2000000
+Sensor at x=3213897, y=2710772: closest beacon is at x=3448416, y=2478759
+Sensor at x=3242113, y=3984214: closest beacon is at x=3264675, y=3635510
+Sensor at x=2809237, y=3782833: closest beacon is at x=2872059, y=3592616
+Sensor at x=2962421, y=37354: closest beacon is at x=3358601, y=-1111474
+Sensor at x=3456740, y=2458922: closest beacon is at x=3448416, y=2478759
+Sensor at x=1799203, y=3569221: closest beacon is at x=2872059, y=3592616
+Sensor at x=3907873, y=3898376: closest beacon is at x=3264675, y=3635510
+Sensor at x=3481951, y=2453964: closest beacon is at x=3448416, y=2478759
+Sensor at x=1120077, y=2963237: closest beacon is at x=1188862, y=2000000
+Sensor at x=2901181, y=3029961: closest beacon is at x=2872059, y=3592616
+Sensor at x=3111105, y=3361570: closest beacon is at x=2964244, y=3612685
+Sensor at x=2533601, y=3956413: closest beacon is at x=2872059, y=3592616
+Sensor at x=108898, y=2275290: closest beacon is at x=1188862, y=2000000
+Sensor at x=3501591, y=2414995: closest beacon is at x=3448416, y=2478759
+Sensor at x=3035657, y=3700769: closest beacon is at x=2964244, y=3612685
+Sensor at x=1286795, y=298997: closest beacon is at x=308571, y=-434280
+Sensor at x=200812, y=3470019: closest beacon is at x=-435606, y=3506717
+Sensor at x=2550124, y=1556776: closest beacon is at x=1188862, y=2000000
+Sensor at x=3955070, y=601908: closest beacon is at x=4076658, y=2478123
+Sensor at x=3565419, y=2355172: closest beacon is at x=3448416, y=2478759"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = r#"Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3"#;
+
+    // The sample asks about row 10 and a 20x20 search area, rather than the real input's sizes.
+
+    #[test]
+    fn part_1_sample() -> Result<()> {
+        assert_eq!(non_beacon_count(&parse(TEST)?, 10)?, 26);
+        Ok(())
+    }
+
+    #[test]
+    fn sample_row_coverage() -> Result<()> {
+        let covered = row_coverage(&parse(TEST)?, 10);
+        assert_eq!(covered.intervals(), &[Interval::new(-2, 24)]);
+        Ok(())
+    }
+
+    #[test]
+    fn part_2_sample() -> Result<()> {
+        assert_eq!(tuning_frequency(&parse(TEST)?, 20)?, 56000011);
+        Ok(())
+    }
+}
@@ -0,0 +1 @@
+aoc_common::bench_day!(16, day16, sample_size = 10);
@@ -0,0 +1,433 @@
+use std::collections::{HashMap, HashSet};
+use std::iter::once;
+use std::str::FromStr;
+
+use anyhow::Result;
+use aoc_common::parse::{self, Located};
+use aoc_common::search;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("line {line}, column {column}: expected a valve report, found {text:?}")]
+    BadValve {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    #[error("line {line}, column {column}: bad flow rate {text:?}")]
+    BadFlow {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    #[error("line {line}, column {column}: tunnel to unknown valve {text:?}")]
+    UnknownValve {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    #[error("there is no valve AA to start from")]
+    NoStart,
+}
+
+impl Located for ParseError {
+    fn below(mut self, lines: usize) -> Self {
+        match &mut self {
+            ParseError::BadValve { line, .. }
+            | ParseError::BadFlow { line, .. }
+            | ParseError::UnknownValve { line, .. } => *line += lines,
+            ParseError::NoStart => {}
+        }
+        self
+    }
+}
+
+/// A valve, its flow rate, and the valves its tunnels lead to.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Valve {
+    pub name: String,
+    pub flow: u32,
+    pub tunnels: Vec<String>,
+}
+
+impl FromStr for Valve {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RE: Regex =
+                Regex::new(r"^Valve (\w+) has flow rate=(\d+); tunnels? leads? to valves? (.*)$")
+                    .unwrap();
+        }
+        let caps = RE.captures(s).ok_or_else(|| ParseError::BadValve {
+            line: 1,
+            column: 1,
+            text: s.to_string(),
+        })?;
+        let name = caps[1].to_string();
+        let flow_match = caps.get(2).unwrap();
+        let flow = flow_match
+            .as_str()
+            .parse()
+            .map_err(|_| ParseError::BadFlow {
+                line: 1,
+                column: flow_match.start() + 1,
+                text: flow_match.as_str().to_string(),
+            })?;
+        let tunnels = caps[3].split(", ").map(|t| t.to_string()).collect();
+        Ok(Valve {
+            name,
+            flow,
+            tunnels,
+        })
+    }
+}
+
+type KnownBest<'a> = HashMap<(u32, &'a str, Vec<&'a str>), (Vec<&'a str>, u32)>;
+type KnownBest2<'a> = HashMap<([u32; 2], [&'a str; 2], Vec<&'a str>), (Vec<(&'a str, u32)>, u32)>;
+
+/// Plans which valves to open, knowing how far apart every valve worth opening is.
+#[derive(Debug, Clone)]
+pub struct Planner {
+    valves: HashMap<String, Valve>,
+    node_distances: HashMap<String, HashMap<String, u32>>,
+}
+
+impl Planner {
+    /// Valves must include `AA`, and every tunnel must lead to one of them, as [`parse`]
+    /// checks.
+    pub fn new(valves: &[Valve]) -> Self {
+        let valves: HashMap<String, Valve> = valves
+            .iter()
+            .cloned()
+            .map(|v: Valve| (v.name.to_string(), v))
+            .collect();
+
+        let targets: Vec<_> = valves
+            .values()
+            .filter(|v| v.flow > 0)
+            .map(|v| v.name.as_str())
+            .collect();
+
+        let node_distances: HashMap<String, HashMap<String, u32>> = targets
+            .iter()
+            .copied()
+            .chain(once("AA"))
+            .map(|t| (t.to_string(), Planner::distances(&valves, t)))
+            .collect();
+
+        Self {
+            valves,
+            node_distances,
+        }
+    }
+
+    /// The most pressure one explorer can release in 30 minutes, starting at `AA`.
+    pub fn plan(&self) -> u32 {
+        let mut known_best: KnownBest = HashMap::new();
+        let targets: Vec<_> = self
+            .valves
+            .values()
+            .filter(|v| v.flow > 0)
+            .map(|v| v.name.as_str())
+            .sorted()
+            .collect();
+
+        self.plan_next(30, "AA", targets.clone(), &mut known_best);
+        let (_, score) = &known_best[&(30, "AA", targets)];
+        *score
+    }
+
+    fn plan_next<'b>(
+        &'b self,
+        time_left: u32,
+        from: &'b str,
+        remaining: Vec<&'b str>,
+        known_best: &mut KnownBest<'b>,
+    ) {
+        let k = (time_left, from, remaining);
+        if !known_best.contains_key(&k) {
+            let next_best =
+                k.2.iter()
+                    .filter_map(|v| {
+                        let cost = self.cost(from, v);
+                        if time_left >= cost {
+                            let rest: Vec<_> = k.2.iter().filter(|w| **w != *v).copied().collect();
+                            let time = time_left - cost;
+                            self.plan_next(time, v, rest.clone(), known_best);
+                            let next_best = &known_best.get(&(time, v, rest));
+                            if let Some(next) = next_best {
+                                let path: Vec<_> = once(*v).chain(next.0.iter().copied()).collect();
+                                let score = self.score(&path, time_left, from);
+
+                                Some((path, score))
+                            } else {
+                                None
+                            }
+                        } else {
+                            None
+                        }
+                    })
+                    .max_by_key(|(_, score)| *score)
+                    .unwrap_or((Vec::new(), 0));
+            known_best.entry(k).or_insert(next_best);
+        }
+    }
+
+    /// The most pressure two explorers can release in 26 minutes, both starting at `AA`.
+    pub fn plan_2(&self) -> u32 {
+        let mut known_best: KnownBest2 = HashMap::new();
+        let targets: Vec<_> = self
+            .valves
+            .values()
+            .filter(|v| v.flow > 0)
+            .map(|v| v.name.as_str())
+            .sorted()
+            .collect();
+
+        self.plan_next_2([26, 26], ["AA", "AA"], targets.clone(), &mut known_best);
+        let (_, score) = &known_best[&([26, 26], ["AA", "AA"], targets)];
+        *score
+    }
+
+    fn plan_next_2<'b>(
+        &'b self,
+        time_left: [u32; 2],
+        from: [&'b str; 2],
+        remaining: Vec<&'b str>,
+        known_best: &mut KnownBest2<'b>,
+    ) {
+        let k = (time_left, from, remaining);
+        if !known_best.contains_key(&k) {
+            let next_best = k
+                .2
+                .iter()
+                .filter_map(|v| {
+                    let i = if time_left[0] < time_left[1] { 1 } else { 0 };
+                    let cost = self.cost(from[i], v);
+                    let time_budget = time_left[i];
+                    let pos = {
+                        let mut pos = from;
+                        pos[i] = v;
+                        pos
+                    };
+                    if time_budget >= cost {
+                        let rest: Vec<_> = k.2.iter().filter(|w| **w != *v).copied().collect();
+                        let time = {
+                            let mut time = time_left;
+                            time[i] = time_budget - cost;
+                            time
+                        };
+                        self.plan_next_2(time, pos, rest.clone(), known_best);
+                        let next_best = &known_best.get(&(time, pos, rest));
+                        if let Some(next) = next_best {
+                            let step = (*v, time_budget - cost);
+                            let path: Vec<_> = once(step).chain(next.0.iter().copied()).collect();
+                            let score = self.score_2(&path);
+
+                            Some((path, score))
+                        } else {
+                            None
+                        }
+                    } else {
+                        None
+                    }
+                })
+                .max_by_key(|(_, score)| *score)
+                .unwrap_or((Vec::new(), 0));
+            known_best.entry(k).or_insert(next_best);
+        }
+    }
+
+    fn score(&self, plan: &[&str], time_left: u32, from: &str) -> u32 {
+        let mut at = from;
+
+        let mut score = 0;
+        let mut t = time_left;
+        for &target in plan {
+            let cost = self.cost(at, target);
+            if t > cost {
+                t -= cost;
+                score += self.valves[target].flow * t;
+                at = target;
+            } else {
+                break;
+            }
+        }
+
+        score
+    }
+
+    fn score_2(&self, plan: &[(&str, u32)]) -> u32 {
+        plan.iter().map(|&(v, t)| self.valves[v].flow * t).sum()
+    }
+
+    fn neighbors<'a>(valves: &'a HashMap<String, Valve>, v: &str) -> impl Iterator<Item = &'a str> {
+        valves[v].tunnels.iter().map(|s| s.as_str())
+    }
+
+    /// The minutes it takes to walk from `from` to `to` and open its valve, or `u32::MAX` if no
+    /// tunnels lead there.
+    fn cost(&self, from: &str, to: &str) -> u32 {
+        self.node_distances[from]
+            .get(to)
+            .map_or(u32::MAX, |d| d + 1)
+    }
+
+    /// How far every valve that can be reached from `start` is; the rest are left out.
+    fn distances(valves: &HashMap<String, Valve>, start: &str) -> HashMap<String, u32> {
+        search::bfs([start], |&v| Planner::neighbors(valves, v), |_| false)
+            .costs()
+            .iter()
+            .map(|(k, d)| (k.to_string(), *d as u32))
+            .collect()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Valve>, ParseError> {
+    let valves: Vec<Valve> = parse::lines(input)?;
+
+    let names: HashSet<&str> = valves.iter().map(|v| v.name.as_str()).collect();
+    if !names.contains("AA") {
+        return Err(ParseError::NoStart);
+    }
+    for (i, (v, l)) in valves.iter().zip(input.lines()).enumerate() {
+        if let Some(t) = v.tunnels.iter().find(|t| !names.contains(t.as_str())) {
+            return Err(ParseError::UnknownValve {
+                line: i + 1,
+                column: l.rfind(t.as_str()).unwrap_or_default() + 1,
+                text: t.to_string(),
+            });
+        }
+    }
+
+    Ok(valves)
+}
+
+pub fn part_1(input: &[Valve]) -> Result<u32> {
+    let planner = Planner::new(input);
+
+    Ok(planner.plan())
+}
+
+pub fn part_2(input: &[Valve]) -> Result<u32> {
+    let planner = Planner::new(input);
+
+    Ok(planner.plan_2())
+}
+
+// VR 11
+// KZ 18
+// AJ 6
+// VG 25
+// IR 10
+// SO 22
+// SC 14
+// RO 19
+// JL 21
+// PW 9
+// DI 23
+// JD 15
+// SP 24
+// OM 7
+// RI 3
+
+pub const INPUT: &str = r#"Valve VR has flow rate=11; tunnels lead to valves LH, KV, BP
+Valve UV has flow rate=0; tunnels lead to valves GH, RO
+Valve OH has flow rate=0; tunnels lead to valves AJ, NY
+Valve GD has flow rate=0; tunnels lead to valves TX, PW
+Valve NS has flow rate=0; tunnels lead to valves AJ, AA
+Valve KZ has flow rate=18; tunnels lead to valves KO, VK, PJ
+Valve AH has flow rate=0; tunnels lead to valves ZP, DI
+Valve SA has flow rate=0; tunnels lead to valves VG, JF
+Valve VK has flow rate=0; tunnels lead to valves RO, KZ
+Valve GB has flow rate=0; tunnels lead to valves XH, AA
+Valve AJ has flow rate=6; tunnels lead to valves IC, OH, ZR, NS, EM
+Valve PJ has flow rate=0; tunnels lead to valves KZ, SP
+Valve KO has flow rate=0; tunnels lead to valves KZ, LE
+Valve AA has flow rate=0; tunnels lead to valves TW, GB, TI, NS, UL
+Valve TW has flow rate=0; tunnels lead to valves TU, AA
+Valve VG has flow rate=25; tunnel leads to valve SA
+Valve BP has flow rate=0; tunnels lead to valves RO, VR
+Valve XH has flow rate=0; tunnels lead to valves GB, RI
+Valve TX has flow rate=0; tunnels lead to valves RI, GD
+Valve IR has flow rate=10; tunnels lead to valves TN, NY, JF
+Valve TU has flow rate=0; tunnels lead to valves JD, TW
+Valve KC has flow rate=0; tunnels lead to valves SP, RO
+Valve LN has flow rate=0; tunnels lead to valves EM, RI
+Valve HD has flow rate=0; tunnels lead to valves FE, SC
+Valve KE has flow rate=0; tunnels lead to valves OM, RI
+Valve VY has flow rate=0; tunnels lead to valves PW, BS
+Valve LH has flow rate=0; tunnels lead to valves OM, VR
+Valve EM has flow rate=0; tunnels lead to valves AJ, LN
+Valve SO has flow rate=22; tunnels lead to valves ZP, FE
+Valve EC has flow rate=0; tunnels lead to valves OM, UL
+Valve KV has flow rate=0; tunnels lead to valves SP, VR
+Valve FE has flow rate=0; tunnels lead to valves SO, HD
+Valve TI has flow rate=0; tunnels lead to valves AA, PW
+Valve SC has flow rate=14; tunnel leads to valve HD
+Valve ZP has flow rate=0; tunnels lead to valves SO, AH
+Valve RO has flow rate=19; tunnels lead to valves UV, BP, VK, KC
+Valve ZR has flow rate=0; tunnels lead to valves OM, AJ
+Valve JL has flow rate=21; tunnels lead to valves GN, TN
+Valve PW has flow rate=9; tunnels lead to valves TI, GN, VY, GD, IC
+Valve UL has flow rate=0; tunnels lead to valves EC, AA
+Valve GN has flow rate=0; tunnels lead to valves JL, PW
+Valve TN has flow rate=0; tunnels lead to valves JL, IR
+Valve NV has flow rate=0; tunnels lead to valves RI, JD
+Valve DI has flow rate=23; tunnels lead to valves LE, AH
+Valve IC has flow rate=0; tunnels lead to valves PW, AJ
+Valve JF has flow rate=0; tunnels lead to valves SA, IR
+Valve LE has flow rate=0; tunnels lead to valves DI, KO
+Valve BS has flow rate=0; tunnels lead to valves JD, VY
+Valve JD has flow rate=15; tunnels lead to valves NV, TU, BS
+Valve SP has flow rate=24; tunnels lead to valves KC, KV, PJ
+Valve NY has flow rate=0; tunnels lead to valves IR, OH
+Valve OM has flow rate=7; tunnels lead to valves EC, GH, KE, ZR, LH
+Valve GH has flow rate=0; tunnels lead to valves OM, UV
+Valve RI has flow rate=3; tunnels lead to valves NV, KE, LN, XH, TX"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = r#"Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II"#;
+
+    #[test]
+    fn part_1_sample() -> Result<()> {
+        assert_eq!(part_1(&parse(TEST)?)?, 1651);
+        Ok(())
+    }
+
+    #[test]
+    fn part_2_sample() -> Result<()> {
+        assert_eq!(part_2(&parse(TEST)?)?, 1707);
+        Ok(())
+    }
+
+    #[test]
+    fn unreachable_valves_are_skipped() -> Result<()> {
+        let input = parse(
+            "Valve AA has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=13; tunnel leads to valve AA
+Valve CC has flow rate=5; tunnel leads to valve DD
+Valve DD has flow rate=0; tunnel leads to valve CC",
+        )?;
+        assert_eq!(part_1(&input)?, 13 * 28);
+        assert_eq!(part_2(&input)?, 13 * 24);
+        Ok(())
+    }
+}
@@ -0,0 +1 @@
+aoc_common::bench_day!(NN, dayNN, no embedded input);
@@ -0,0 +1,62 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use aoc_common::parse::{self, Located};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("line {line}: bad record {text:?}")]
+    BadRecord { line: usize, text: String },
+}
+
+impl Located for ParseError {
+    fn below(mut self, lines: usize) -> Self {
+        match &mut self {
+            ParseError::BadRecord { line, .. } => *line += lines,
+        }
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct Record;
+
+impl FromStr for Record {
+    type Err = ParseError;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Record)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+    parse::lines(input)
+}
+
+pub fn part_1(input: &[Record]) -> Result<usize> {
+    Ok(input.len())
+}
+
+pub fn part_2(_input: &[Record]) -> Result<usize> {
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = r#""#;
+
+    #[test]
+    fn part_1_sample() -> Result<()> {
+        assert_eq!(part_1(&parse(TEST)?)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn part_2_sample() -> Result<()> {
+        assert_eq!(part_2(&parse(TEST)?)?, 0);
+        Ok(())
+    }
+}
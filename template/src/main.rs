@@ -1,22 +1,17 @@
-use anyhow::Result;
-use std::str::FromStr;
-
-#[derive(Debug)]
-struct Record;
+use std::process::ExitCode;
 
-impl FromStr for Record {
-    type Err = anyhow::Error;
+use anyhow::Result;
 
-    fn from_str(_s: &str) -> Result<Self, Self::Err> {
-        Ok(Record)
-    }
+fn main() -> ExitCode {
+    aoc_common::report(run())
 }
 
-fn main() -> Result<()> {
-    let input: Vec<Record> = INPUT.lines().map(|l| l.parse().unwrap()).collect();
+fn run() -> Result<()> {
+    let text = aoc_common::input::load(NN, None)?;
+    let input = dayNN::parse(&text)?;
+
+    println!("Part 1: {}", dayNN::part_1(&input)?);
+    println!("Part 2: {}", dayNN::part_2(&input)?);
 
-    dbg!(&input);
     Ok(())
 }
-
-const INPUT: &str = r#""#;